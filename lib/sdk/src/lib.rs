@@ -1,3 +1,4 @@
+use std::ffi::{c_char, CStr};
 use std::ptr::null;
 use std::str::FromStr;
 
@@ -35,3 +36,10 @@ pub extern "C" fn sdk_read_int() -> u32 {
     }
 }
 
+// called by bounds checks when an array or string index is out of range
+#[no_mangle]
+pub extern "C" fn sdk_panic_bounds(index: i32, length: i32, file: *const c_char, line: u32) -> ! {
+    let file = unsafe { CStr::from_ptr(file) }.to_string_lossy();
+    eprintln!("{file}:{line}: index {index} out of bounds for length {length}");
+    std::process::exit(101);
+}
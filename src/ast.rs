@@ -24,8 +24,10 @@ impl AbstractSyntaxTree {
         });
     }
 
-    pub fn new(module: Rc<Module>) -> Result<Rc<Self>, ASTError> {
-        return Ok(Self::newFrom(parseModule(module)?));
+    // returns the tree of all symbols which were successfully parsed, and errors for those which were not
+    pub fn new(module: Rc<Module>) -> (Rc<Self>, Vec<ASTError>) {
+        let (symbolVec, errorVec) = parseModule(module);
+        return (Self::newFrom(symbolVec), errorVec);
     }
 
     pub fn getSymbols(&self) -> &Vec<Symbol> {
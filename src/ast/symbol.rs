@@ -4,11 +4,15 @@ use crate::ast::symbol::block::BlockSym;
 use crate::ast::symbol::breaksym::BreakSym;
 use crate::ast::symbol::classdefinition::ClassDefinitionSym;
 use crate::ast::symbol::continuesym::ContinueSym;
+use crate::ast::symbol::enumdefinition::EnumDefinitionSym;
 use crate::ast::symbol::expr::{Expr, ExprType};
 use crate::ast::symbol::function::FunctionDefinitionSym;
 use crate::ast::symbol::ifstatement::IfSym;
 use crate::ast::symbol::import::ImportSym;
+use crate::ast::symbol::looptype::forloop::ForLoop;
+use crate::ast::symbol::looptype::r#loop::Loop;
 use crate::ast::symbol::looptype::whileloop::WhileLoop;
+use crate::ast::symbol::matchsym::MatchSym;
 use crate::ast::symbol::printsym::PrintSym;
 use crate::ast::symbol::returnsym::ReturnSym;
 use crate::module::modulepos::ModuleRange;
@@ -24,6 +28,8 @@ pub mod ifstatement;
 pub mod continuesym;
 pub mod returnsym;
 pub mod printsym;
+pub mod enumdefinition;
+pub mod matchsym;
 
 pub trait SymbolType: Debug {
     fn getRange(&self) -> &ModuleRange;
@@ -37,12 +43,14 @@ pub enum Symbol {
     Break(BreakSym),
     Continue(ContinueSym),
     While(WhileLoop),
-    // Loop(Loop),
-    // For(ForLoop),
+    Loop(Loop),
+    For(ForLoop),
     Return(ReturnSym),
     IfSym(IfSym),
+    Match(MatchSym),
     // structures
     ClassDefinition(ClassDefinitionSym),
+    EnumDefinition(EnumDefinitionSym),
     FunctionDefinition(FunctionDefinitionSym),
     ImportSym(ImportSym),
     PrintSym(PrintSym),
@@ -56,15 +64,20 @@ pub enum SymbolDiscriminants {
     Break,
     Continue,
     While,
+    Loop,
+    For,
     Return,
     IfSym,
+    Match,
     ClassDefinition,
+    EnumDefinition,
     FunctionDefinition,
     ImportSym,
     PrintSym,
     FunctionCall,
     Operator,
     VariableDeclaration,
+    TupleDeclaration,
     Variable,
     Read,
     LiteralArray,
@@ -85,9 +98,13 @@ impl<'a> From<&'a Symbol> for SymbolDiscriminants {
             Symbol::Break(_) => SymbolDiscriminants::Break,
             Symbol::Continue(_) => SymbolDiscriminants::Continue,
             Symbol::While(_) => SymbolDiscriminants::While,
+            Symbol::Loop(_) => SymbolDiscriminants::Loop,
+            Symbol::For(_) => SymbolDiscriminants::For,
             Symbol::Return(_) => SymbolDiscriminants::Return,
             Symbol::IfSym(_) => SymbolDiscriminants::IfSym,
+            Symbol::Match(_) => SymbolDiscriminants::Match,
             Symbol::ClassDefinition(_) => SymbolDiscriminants::ClassDefinition,
+            Symbol::EnumDefinition(_) => SymbolDiscriminants::EnumDefinition,
             Symbol::FunctionDefinition(_) => SymbolDiscriminants::FunctionDefinition,
             Symbol::ImportSym(_) => SymbolDiscriminants::ImportSym,
             Symbol::PrintSym(_) => SymbolDiscriminants::PrintSym,
@@ -96,6 +113,7 @@ impl<'a> From<&'a Symbol> for SymbolDiscriminants {
                     Expr::FunctionCall(_) => SymbolDiscriminants::FunctionCall,
                     Expr::Operator(_) => SymbolDiscriminants::Operator,
                     Expr::VariableDeclaration(_) => SymbolDiscriminants::VariableDeclaration,
+                    Expr::TupleDeclaration(_) => SymbolDiscriminants::TupleDeclaration,
                     Expr::Variable(_) => SymbolDiscriminants::Variable,
                     Expr::ReadExpr(_) => SymbolDiscriminants::Read,
                     Expr::LiteralArray(_) => SymbolDiscriminants::LiteralArray,
@@ -118,14 +136,16 @@ impl Symbol {
             Symbol::Block(symbol) => symbol,
             Symbol::Break(symbol) => symbol,
             Symbol::ClassDefinition(symbol) => symbol,
+            Symbol::EnumDefinition(symbol) => symbol,
             Symbol::FunctionDefinition(symbol) => symbol,
             Symbol::IfSym(symbol) => symbol,
+            Symbol::Match(symbol) => symbol,
             Symbol::ImportSym(symbol) => symbol,
             Symbol::PrintSym(symbol) => symbol,
             Symbol::Continue(symbol) => symbol,
             Symbol::While(symbol) => symbol,
-            // Symbol::Loop(symbol) => symbol,
-            // Symbol::For(symbol) => symbol,
+            Symbol::Loop(symbol) => symbol,
+            Symbol::For(symbol) => symbol,
             Symbol::Return(symbol) => symbol,
             Symbol::Expr(expr) => expr.getExprType().getSymbolType(),
         };
@@ -11,14 +11,14 @@ pub struct ClassFieldDefinition {
     pub name: ModulePos,
     pub visibility: Visibility,
     // typeName or defaultValue (or both) MUST be Some
-    pub typeName: Option<ModulePos>,
+    pub typeName: Option<ModuleRange>,
     pub defaultValue: Option<Expr>,
 }
 
 #[derive(Debug)]
 pub struct ClassStaticFieldDefinition {
     pub name: ModulePos,
-    pub typeName: Option<ModulePos>,
+    pub typeName: Option<ModuleRange>,
     pub visibility: Visibility,
     pub defaultValue: Option<Expr>,
 }
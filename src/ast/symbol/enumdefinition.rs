@@ -0,0 +1,18 @@
+use crate::ast::symbol::SymbolType;
+use crate::ast::visibility::Visibility;
+use crate::module::modulepos::{ModulePos, ModuleRange};
+
+#[derive(Debug)]
+pub struct EnumDefinitionSym {
+    pub visibility: Visibility,
+    pub range: ModuleRange,
+    pub name: ModulePos,
+    // variants are numbered in declaration order
+    pub variants: Vec<ModulePos>,
+}
+
+impl SymbolType for EnumDefinitionSym {
+    fn getRange(&self) -> &ModuleRange {
+        return &self.range;
+    }
+}
@@ -11,6 +11,7 @@ use crate::ast::symbol::expr::literal::literalstring::LiteralString;
 use crate::ast::symbol::expr::literal::literaltuple::LiteralTuple;
 use crate::ast::symbol::expr::operatorexpr::OperatorExpr;
 use crate::ast::symbol::expr::readexpr::ReadExpr;
+use crate::ast::symbol::expr::tupledeclaration::TupleDeclarationExpr;
 use crate::ast::symbol::expr::variabledeclaration::VariableDeclarationExpr;
 use crate::ast::symbol::expr::variableexpr::VariableExpr;
 use crate::module::modulepos::ModuleRange;
@@ -21,6 +22,7 @@ pub mod variableexpr;
 pub mod literal;
 pub mod variabledeclaration;
 pub mod readexpr;
+pub mod tupledeclaration;
 
 pub trait ExprType: 'static + SymbolType + Debug {
     fn getSymbolType(&self) -> &dyn SymbolType;
@@ -31,6 +33,7 @@ pub enum Expr {
     FunctionCall(FunctionCallExpr),
     Operator(OperatorExpr),
     VariableDeclaration(VariableDeclarationExpr),
+    TupleDeclaration(TupleDeclarationExpr),
     Variable(VariableExpr),
     ReadExpr(ReadExpr),
     // ConstructorCall(ConstructorCallExpr),
@@ -57,6 +60,7 @@ impl Expr {
             Expr::FunctionCall(v) => v,
             Expr::Operator(v) => v,
             Expr::VariableDeclaration(v) => v,
+            Expr::TupleDeclaration(v) => v,
             Expr::Variable(v) => v,
             Expr::ReadExpr(v) => v,
             Expr::LiteralArray(v) => v,
@@ -51,55 +51,77 @@ impl OperatorExpr {
         };
     }
 
-    fn getValidComponents(mut components: Vec<OperationComponent>) -> Result<Vec<OperationComponent>, ASTError> {
-        #[derive(Copy, Clone)]
-        enum NextComponent {
-            Operator,
-            Operand,
-            Either,
-        }
+    fn isLineBreakBetween(first: &ModuleRange, second: &ModuleRange) -> bool {
+        let (first, second) = (first.getFileRange(), second.getFileRange());
+        let source = first.getStart().getSourceFile().getSource();
+        return source.get(first.getEndIndex()..second.getStartIndex()).is_some_and(|between| between.contains('\n'));
+    }
 
-        let mut index = 0;
+    fn getValidComponents(mut components: Vec<OperationComponent>) -> Result<Vec<OperationComponent>, ASTError> {
         let mut lastValidIndex = 0;
-        let mut nextComponent = NextComponent::Either;
-        let mut difference = 0;
+        // an operand is expected at the start of the expression, and after binary or prefix operators
+        let mut expectOperand = true;
+        let mut previousRange: Option<ModuleRange> = None;
+        // positions of square parenthesis following an operand (a[i]), rather than an array literal
+        let mut indexVec = Vec::new();
 
-        for component in &components {
+        for (index, component) in components.iter_mut().enumerate() {
             match component {
-                OperationComponent::Operator(_, operator) => {
-                    if let NextComponent::Operand = nextComponent {
+                OperationComponent::Operator(range, operator) => {
+                    if expectOperand {
+                        // operator without a left operand (e.g. minus is negation)
+                        if let Some(prefixOperator) = operator.getPrefixOperator() {
+                            *operator = prefixOperator;
+                        }
+                        if !operator.isPrefix() {
+                            break;
+                        }
+                    } else if operator.isPrefix() {
+                        break;
+                    } else if operator.getOperands() == 1 {
+                        // a++ followed by ++b on the next line is two separate expressions
+                        if operator.getPrefixOperator().is_some() && previousRange.as_ref().is_some_and(|previousRange| Self::isLineBreakBetween(previousRange, range)) {
+                            break;
+                        }
+                        // postfix operator, expression is still valid
+                        lastValidIndex = index + 1;
+                    } else {
+                        expectOperand = true;
+                    }
+                    previousRange = Some(range.to_owned());
+                }
+                OperationComponent::Expression(Expr::LiteralArray(array)) if !expectOperand && array.exprVec.len() == 1 => {
+                    // a[i] followed by [j] on the next line is an array literal
+                    if previousRange.as_ref().is_some_and(|previousRange| Self::isLineBreakBetween(previousRange, &array.range)) {
                         break;
                     }
-                    difference += operator.getOperands() as i32;
+                    indexVec.push(index);
+                    lastValidIndex = index + 1;
+                    previousRange = Some(array.range.to_owned());
                 }
-                OperationComponent::Expression(_) => {
-                    if let NextComponent::Operator = nextComponent {
+                OperationComponent::Expression(expr) => {
+                    if !expectOperand {
                         break;
                     }
-                    difference -= 1;
+                    expectOperand = false;
+                    lastValidIndex = index + 1;
+                    previousRange = Some(expr.getRange().to_owned());
                 }
             }
+        }
 
-            index += 1;
+        components.truncate(lastValidIndex);
 
-            nextComponent = match difference.cmp(&0) {
-                Ordering::Less => {
-                    lastValidIndex = index;
-                    NextComponent::Operator
-                }
-                Ordering::Equal => {
-                    lastValidIndex = index;
-                    // if difference is 0 the operator expression is valid
-                    // operator expression is, itself, an expression to be used
-                    difference -= 1;
-                    NextComponent::Operator
-                }
-                Ordering::Greater => NextComponent::Operand
+        // the index remains wrapped in the parenthesis expression, so that its range is in the same module as the indexed value
+        let mut validComponents = Vec::new();
+        for (index, component) in components.into_iter().enumerate() {
+            if indexVec.contains(&index) {
+                let OperationComponent::Expression(expr) = &component else { unreachable!() };
+                validComponents.push(OperationComponent::Operator(expr.getRange().to_owned(), Operator::Index));
             }
+            validComponents.push(component);
         }
-
-        components.resize_with(lastValidIndex, || unreachable!());
-        return Ok(components);
+        return Ok(validComponents);
     }
 
     pub fn getFromInfix(components: Vec<OperationComponent>) -> Result<Self, ASTError> {
@@ -123,6 +145,10 @@ impl OperatorExpr {
                 OperationComponent::Expression(expression) => {
                     resultQueue.push(OperationComponent::Expression(expression));
                 }
+                OperationComponent::Operator(range, operator) if operator.isPrefix() => {
+                    // prefix operators are applied after their operand has been evaluated
+                    operatorStack.push((range, operator));
+                }
                 OperationComponent::Operator(range, operator) => {
                     loop {
                         if let Some((lastRange, lastOperator)) = operatorStack.pop() {
@@ -206,6 +232,7 @@ mod test {
 
     use crate::ast::ASTError;
     use crate::ast::symbol::expr::Expr;
+    use crate::ast::symbol::expr::literal::literalarray::LiteralArray;
     use crate::ast::symbol::expr::literal::literalinteger::LiteralInteger;
     use crate::ast::symbol::expr::operatorexpr::{OperationComponent, OperatorExpr};
     use crate::module::{FilePos, FileRange, Module, Operator, SourceFile, Token, TokenType};
@@ -250,7 +277,7 @@ mod test {
         assert_eq!(expected.operands.len(), provided.operands.len(), "operand mismatch, expected {:?} operands, found {:?}\nExpected:\n{expected:#?}\n\nProvided:\n{provided:#?}", expected.operands.len(), provided.operands.len());
 
         for index in 0..expected.operands.len() {
-            if expected.operands[index].downcast_ref::<LiteralInteger>().is_some() {
+            if matches!(expected.operands[index], Expr::LiteralInteger(_)) {
                 assert_eq!(expected.operands[index].getRange(), provided.operands[index].getRange(), "operand mismatch, expected {:?}, found {:?}\nExpected:\n{expected:#?}\n\nProvided:\n{provided:#?}", expected.operands[index], provided.operands[index]);
             }
 
@@ -410,6 +437,86 @@ mod test {
         checkEq(expected, expr);
     }
 
+    #[test]
+    fn testNegateMultiplication() {
+        // -a * b
+        // (-a) * b
+        let expr = OperatorExpr::getFromInfix(vec![
+            getOperatorComponent(0, Operator::Minus),
+            getExprComponent(1),
+            getOperatorComponent(2, Operator::Mult),
+            getExprComponent(3),
+        ]);
+
+        let expected = OperatorExpr::binaryExpr(
+            Expr::Operator(OperatorExpr::unaryOperator(getExpr(1), Operator::Negate, getPosIndex(0).getRangeWithLength(1))),
+            Operator::Mult,
+            getExpr(3),
+        );
+
+        checkEq(expected, expr);
+    }
+
+    #[test]
+    fn testMinusNegate() {
+        // a - -b
+        // a - (-b)
+        let expr = OperatorExpr::getFromInfix(vec![
+            getExprComponent(0),
+            getOperatorComponent(1, Operator::Minus),
+            getOperatorComponent(2, Operator::Minus),
+            getExprComponent(3),
+        ]);
+
+        let expected = OperatorExpr::binaryExpr(
+            getExpr(0),
+            Operator::Minus,
+            Expr::Operator(OperatorExpr::unaryOperator(getExpr(3), Operator::Negate, getPosIndex(2).getRangeWithLength(1))),
+        );
+
+        checkEq(expected, expr);
+    }
+
+    #[test]
+    fn testNotComparison() {
+        // !a == b
+        // (!a) == b
+        let expr = OperatorExpr::getFromInfix(vec![
+            getOperatorComponent(0, Operator::Not),
+            getExprComponent(1),
+            getOperatorComponent(2, Operator::CompareEq),
+            getExprComponent(3),
+        ]);
+
+        let expected = OperatorExpr::binaryExpr(
+            Expr::Operator(OperatorExpr::unaryOperator(getExpr(1), Operator::Not, getPosIndex(0).getRangeWithLength(1))),
+            Operator::CompareEq,
+            getExpr(3),
+        );
+
+        checkEq(expected, expr);
+    }
+
+    #[test]
+    fn testPrefixIncrementMultiplication() {
+        // ++a * b
+        // (++a) * b
+        let expr = OperatorExpr::getFromInfix(vec![
+            getOperatorComponent(0, Operator::Increment),
+            getExprComponent(1),
+            getOperatorComponent(2, Operator::Mult),
+            getExprComponent(3),
+        ]);
+
+        let expected = OperatorExpr::binaryExpr(
+            Expr::Operator(OperatorExpr::unaryOperator(getExpr(1), Operator::PrefixIncrement, getPosIndex(0).getRangeWithLength(1))),
+            Operator::Mult,
+            getExpr(3),
+        );
+
+        checkEq(expected, expr);
+    }
+
     #[test]
     fn testInvalidNoOperators() {
         // a
@@ -485,4 +592,28 @@ mod test {
 
         checkEq(expected, expr);
     }
+
+    #[test]
+    fn testIndexNegateMultiplication() {
+        // -a[b] * c
+        // (-(a[b])) * c
+        let index = Expr::LiteralArray(LiteralArray {
+            range: getPosIndex(2).getRangeWithLength(1),
+            exprVec: vec![getExpr(2)],
+        });
+        let expr = OperatorExpr::getFromInfix(vec![
+            getOperatorComponent(0, Operator::Minus),
+            getExprComponent(1),
+            OperationComponent::Expression(index),
+            getOperatorComponent(3, Operator::Mult),
+            getExprComponent(4),
+        ]).unwrap();
+
+        assert_eq!(Operator::Mult, expr.operator);
+        let Expr::Operator(negate) = &expr.operands[0] else { panic!("expected negation, found {:?}", expr.operands[0]) };
+        assert_eq!(Operator::Negate, negate.operator);
+        let Expr::Operator(index) = &negate.operands[0] else { panic!("expected index, found {:?}", negate.operands[0]) };
+        assert_eq!(Operator::Index, index.operator);
+        assert!(matches!(index.operands[1], Expr::LiteralArray(_)));
+    }
 }
@@ -0,0 +1,21 @@
+use crate::ast::symbol::expr::ExprType;
+use crate::ast::symbol::SymbolType;
+use crate::module::modulepos::{ModulePos, ModuleRange};
+
+#[derive(Debug)]
+pub struct TupleDeclarationExpr {
+    pub range: ModuleRange,
+    pub variableNames: Vec<ModulePos>,
+}
+
+impl SymbolType for TupleDeclarationExpr {
+    fn getRange(&self) -> &ModuleRange {
+        return &self.range;
+    }
+}
+
+impl ExprType for TupleDeclarationExpr {
+    fn getSymbolType(&self) -> &dyn SymbolType {
+        return self;
+    }
+}
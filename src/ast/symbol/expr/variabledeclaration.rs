@@ -6,7 +6,7 @@ use crate::module::modulepos::{ModulePos, ModuleRange};
 pub struct VariableDeclarationExpr {
     pub range: ModuleRange,
     pub variableName: ModulePos,
-    pub explicitType: Option<ModulePos>,
+    pub explicitType: Option<ModuleRange>,
 }
 
 impl SymbolType for VariableDeclarationExpr {
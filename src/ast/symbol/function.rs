@@ -7,7 +7,7 @@ use crate::module::modulepos::{ModulePos, ModuleRange};
 
 #[derive(Debug)]
 pub struct FunctionParameter {
-    pub typeName: ModulePos,
+    pub typeName: ModuleRange,
     pub parameterName: ModulePos,
     pub defaultExpr: Option<Expr>,
 }
@@ -30,7 +30,7 @@ impl FunctionAttribute {
 pub struct FunctionDefinitionSym {
     pub range: ModuleRange,
     pub attributeVec: Vec<FunctionAttribute>,
-    pub returnType: ModulePos,
+    pub returnType: ModuleRange,
     pub functionName: ModulePos,
     pub parameters: Vec<FunctionParameter>,
     pub functionBlock: BlockSym,
@@ -1,12 +1,17 @@
+use crate::ast::symbol::{Symbol, SymbolType};
+use crate::ast::symbol::expr::Expr;
 use crate::ast::symbol::looptype::label::Label;
 use crate::ast::symbol::looptype::LoopType;
-use crate::ast::symbol::SymbolType;
-use crate::module::modulepos::ModuleRange;
+use crate::module::modulepos::{ModulePos, ModuleRange};
 
 #[derive(Debug)]
 pub struct ForLoop {
-    range: ModuleRange,
-    label: Option<Label>,
+    pub range: ModuleRange,
+    pub label: Option<Label>,
+    pub variable: ModulePos,
+    // range of values assigned to variable (e.g. 0..10)
+    pub iterable: Expr,
+    pub symbol: Box<Symbol>,
 }
 
 impl SymbolType for ForLoop {
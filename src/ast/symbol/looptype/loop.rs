@@ -1,12 +1,13 @@
+use crate::ast::symbol::{Symbol, SymbolType};
 use crate::ast::symbol::looptype::label::Label;
 use crate::ast::symbol::looptype::LoopType;
-use crate::ast::symbol::SymbolType;
 use crate::module::modulepos::ModuleRange;
 
 #[derive(Debug)]
 pub struct Loop {
-    range: ModuleRange,
-    label: Option<Label>,
+    pub range: ModuleRange,
+    pub label: Option<Label>,
+    pub symbol: Box<Symbol>,
 }
 
 impl SymbolType for Loop {
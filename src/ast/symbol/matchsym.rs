@@ -0,0 +1,25 @@
+use crate::ast::symbol::{Symbol, SymbolType};
+use crate::ast::symbol::expr::Expr;
+use crate::ast::symbol::ifstatement::ElseSym;
+use crate::module::modulepos::{ModulePos, ModuleRange};
+
+#[derive(Debug)]
+pub struct MatchCase {
+    pub variant: ModulePos,
+    pub symbol: Symbol,
+}
+
+#[derive(Debug)]
+pub struct MatchSym {
+    pub range: ModuleRange,
+    pub value: Expr,
+    pub caseVec: Vec<MatchCase>,
+    // taken by any variant without a case
+    pub elseSym: Option<Box<ElseSym>>,
+}
+
+impl SymbolType for MatchSym {
+    fn getRange(&self) -> &ModuleRange {
+        return &self.range;
+    }
+}
@@ -1,7 +1,10 @@
 use std::cmp::min;
 use std::rc::Rc;
 
+use strum_macros::IntoStaticStr;
+
 use crate::ast::symbol::Symbol;
+use crate::diagnostic::{Diagnostic, Severity};
 use crate::ast::tokensource::tokenparser::parseTokenVec;
 use crate::module::{Module, Operator, TokenType, TokenTypeDiscriminants};
 use crate::module::modulepos::ModulePos;
@@ -11,6 +14,7 @@ pub mod tokenparser;
 pub mod matchtype;
 mod matchers;
 
+#[derive(IntoStaticStr)]
 #[derive(Debug)]
 pub enum ASTError {
     // miscellaneous
@@ -98,12 +102,28 @@ impl ASTError {
         return (source.replace('\n', " ").replace('\r', ""), sourceIndex);
     }
 
+    pub fn getDiagnostic(&self) -> Diagnostic {
+        return match self {
+            ASTError::MatchOptionsFailed(pos, options) => {
+                let mut diagnostic = Diagnostic::error(format!("all potential matches failed"), Some(pos.getFileRange())).withCode(self.into());
+                for (description, err) in options {
+                    let mut note = err.getDiagnostic();
+                    note.severity = Severity::Note;
+                    note.message = format!("{description}: {}", note.message);
+                    diagnostic = diagnostic.withNote(note);
+                }
+                diagnostic
+            }
+            _ => Diagnostic::error(self.getErrorMessage(), Some(self.getModulePos().getFileRange())).withCode(self.into()),
+        };
+    }
+
     pub fn getDisplayMessage(&self) -> String {
         let (source, index) = self.getTokenSource();
         return format!("error: {}\n\t(at {:?})\n\t> \"{}\"\n\t {}", self.getErrorMessage(), self.getModulePos(), source, " ".repeat(index) + "^");
     }
 }
 
-pub fn parseModule(module: Rc<Module>) -> Result<Vec<Symbol>, ASTError> {
+pub fn parseModule(module: Rc<Module>) -> (Vec<Symbol>, Vec<ASTError>) {
     return parseTokenVec(module);
 }
@@ -117,12 +117,20 @@ pub fn resolveSymbolConflict<'a>(pos: ModulePos, options: impl Iterator<Item = &
     resolver.setPreferred(SymbolDiscriminants::VariableDeclaration, SymbolDiscriminants::Variable);
     resolver.setPreferred(SymbolDiscriminants::FunctionDefinition, SymbolDiscriminants::VariableDeclaration);
     resolver.setPreferred(SymbolDiscriminants::FunctionDefinition, SymbolDiscriminants::LiteralVoid);
+    // tuple types are written like tuple literals, e.g. (int, float) name
+    resolver.setPreferred(SymbolDiscriminants::VariableDeclaration, SymbolDiscriminants::LiteralTuple);
+    resolver.setPreferred(SymbolDiscriminants::FunctionDefinition, SymbolDiscriminants::LiteralTuple);
 
     resolver.setPreferred(SymbolDiscriminants::While, SymbolDiscriminants::Operator);
     resolver.setPreferred(SymbolDiscriminants::While, SymbolDiscriminants::Variable);
+    resolver.setPreferred(SymbolDiscriminants::Loop, SymbolDiscriminants::Operator);
+    resolver.setPreferred(SymbolDiscriminants::Loop, SymbolDiscriminants::Variable);
+    resolver.setPreferred(SymbolDiscriminants::For, SymbolDiscriminants::Operator);
+    resolver.setPreferred(SymbolDiscriminants::For, SymbolDiscriminants::Variable);
 
     resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::FunctionCall);
     resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::VariableDeclaration);
+    resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::TupleDeclaration);
     resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::Variable);
     resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::LiteralArray);
     resolver.setPreferredOnlyIfLonger(SymbolDiscriminants::Operator, SymbolDiscriminants::LiteralBool);
@@ -8,6 +8,7 @@ use crate::ast::symbol::block::BlockSym;
 use crate::ast::symbol::breaksym::BreakSym;
 use crate::ast::symbol::classdefinition::{ClassDefinitionSym, ClassFieldDefinition, ClassMember, ClassStaticFieldDefinition};
 use crate::ast::symbol::continuesym::ContinueSym;
+use crate::ast::symbol::enumdefinition::EnumDefinitionSym;
 use crate::ast::symbol::expr::Expr;
 use crate::ast::symbol::expr::functioncall::FunctionCallExpr;
 use crate::ast::symbol::expr::literal::literalarray::LiteralArray;
@@ -19,13 +20,17 @@ use crate::ast::symbol::expr::literal::literalstring::LiteralString;
 use crate::ast::symbol::expr::literal::literaltuple::LiteralTuple;
 use crate::ast::symbol::expr::operatorexpr::{OperationComponent, OperatorExpr};
 use crate::ast::symbol::expr::readexpr::ReadExpr;
+use crate::ast::symbol::expr::tupledeclaration::TupleDeclarationExpr;
 use crate::ast::symbol::expr::variabledeclaration::VariableDeclarationExpr;
 use crate::ast::symbol::expr::variableexpr::VariableExpr;
 use crate::ast::symbol::function::{FunctionAttribute, FunctionDefinitionSym, FunctionParameter};
 use crate::ast::symbol::ifstatement::{ElseSym, IfSym};
 use crate::ast::symbol::import::ImportSym;
+use crate::ast::symbol::looptype::forloop::ForLoop;
 use crate::ast::symbol::looptype::label::Label;
+use crate::ast::symbol::looptype::r#loop::Loop;
 use crate::ast::symbol::looptype::whileloop::WhileLoop;
+use crate::ast::symbol::matchsym::{MatchCase, MatchSym};
 use crate::ast::symbol::printsym::PrintSym;
 use crate::ast::symbol::returnsym::ReturnSym;
 use crate::ast::tokensource::conflictresolution::{resolveClassDefinitionConflict, resolveSymbolConflict};
@@ -78,13 +83,39 @@ pub fn getMatchVisibility() -> impl MatchType<Value = Visibility> {
     });
 }
 
-pub fn getMatchType() -> impl MatchType<Value = ModulePos> {
-    return getMatchFrom(format!("type"), |pos| {
-        return match pos.getToken().getTokenType() {
-            TokenType::Keyword(Keyword::Void) | TokenType::Identifier => Ok(Match::new(pos.getRangeWithLength(1), pos)),
-            _ => Err(ASTError::ExpectedType(pos)),
-        };
-    });
+pub fn getMatchType() -> impl MatchType<Value = ModuleRange> {
+    // type
+    // type[length] (array, e.g. int[4] or int[2][3])
+    // (type, type) (tuple, e.g. (int, float))
+    return getMappedMatch(
+        (
+            getMatchFrom(format!("type"), |pos| {
+                return match pos.getToken().getTokenType() {
+                    TokenType::Keyword(Keyword::Void) | TokenType::Identifier => Ok(Match::new(pos.getRangeWithLength(1), pos)),
+                    TokenType::Parenthesis(ParenthesisType::Rounded, module) => {
+                        // each element of the comma list must be exactly one type
+                        let [token] = module.getTokenVector().as_slice() else { return Err(ASTError::ExpectedType(pos)) };
+                        let TokenType::CommaList(moduleVec) = token.getTokenType() else { return Err(ASTError::ExpectedType(pos)) };
+                        for module in moduleVec {
+                            let elementType = getMatchType().getMatch(module.getModulePos(0))?;
+                            if elementType.getRange().getEndIndex() != module.getTokenVector().len() {
+                                return Err(ASTError::ExpectedExclusive(elementType.getRange().getEndPos(), None));
+                            }
+                        }
+                        Ok(Match::new(pos.getRangeWithLength(1), pos))
+                    }
+                    _ => Err(ASTError::ExpectedType(pos)),
+                };
+            }),
+            getRepeatingMatch(0, getMatchParenthesis(ParenthesisType::Square, |module| {
+                let length = getMatchLiteralInteger().getMatch(module.getModulePos(0))?;
+                if length.getRange().getEndIndex() != module.getTokenVector().len() {
+                    return Err(ASTError::ExpectedExclusive(length.getRange().getEndPos(), None));
+                }
+                return Ok(length.take().1);
+            })),
+        ), |range, _| Ok(range),
+    );
 }
 
 pub fn getMatchIdentifier() -> impl MatchType<Value = ModulePos> {
@@ -166,15 +197,20 @@ pub fn getMatchSymbol() -> impl MatchType<Value = Symbol> {
         MatchOption::new(getMatchBreakSym(), |_, v| Ok(Symbol::Break(v))),
         MatchOption::new(getMatchContinueSym(), |_, v| Ok(Symbol::Continue(v))),
         MatchOption::new(getMatchClassDefinitionSym(), |_, v| Ok(Symbol::ClassDefinition(v))),
+        MatchOption::new(getMatchEnumDefinitionSym(), |_, v| Ok(Symbol::EnumDefinition(v))),
         MatchOption::new(getMatchFunctionDefinitionSym(), |_, v| Ok(Symbol::FunctionDefinition(v))),
         MatchOption::new(getMatchIfSym(), |_, v| Ok(Symbol::IfSym(v))),
+        MatchOption::new(getMatchMatchSym(), |_, v| Ok(Symbol::Match(v))),
         MatchOption::new(getMatchWhileSym(), |_, v| Ok(Symbol::While(v))),
+        MatchOption::new(getMatchLoopSym(), |_, v| Ok(Symbol::Loop(v))),
+        MatchOption::new(getMatchForSym(), |_, v| Ok(Symbol::For(v))),
         MatchOption::new(getMatchReturnSym(), |_, v| Ok(Symbol::Return(v))),
         MatchOption::new(getMatchImportSym(), |_, v| Ok(Symbol::ImportSym(v))),
         MatchOption::new(getMatchPrintSym(), |_, v| Ok(Symbol::PrintSym(v))),
         MatchOption::new(getMatchFunctionCallExpr(), |_, v| Ok(Symbol::Expr(Expr::FunctionCall(v)))),
         MatchOption::new(getMatchOperatorExpr(), |_, v| Ok(Symbol::Expr(Expr::Operator(v)))),
         MatchOption::new(getMatchVariableDeclarationExpr(), |_, v| Ok(Symbol::Expr(Expr::VariableDeclaration(v)))),
+        MatchOption::new(getMatchTupleDeclarationExpr(), |_, v| Ok(Symbol::Expr(Expr::TupleDeclaration(v)))),
         MatchOption::new(getMatchReadExpr(), |_, v| Ok(Symbol::Expr(Expr::ReadExpr(v)))),
         MatchOption::new(getMatchVariableExpr(), |_, v| Ok(Symbol::Expr(Expr::Variable(v)))),
         MatchOption::new(getMatchLiteralArray(), |_, v| Ok(Symbol::Expr(Expr::LiteralArray(v)))),
@@ -213,6 +249,11 @@ pub fn getMatchExcludingExpr(excludeOperator: bool, excludeDeclaration: bool) ->
         } else {
             MatchOption::new(getMatchFrom(format!("NOP"), |pos| Err(ASTError::MatchFailed(pos))), |pos, _: u8| Err(ASTError::MatchFailed(pos.getStartPos())))
         },
+        if !excludeDeclaration {
+            MatchOption::new(getMatchTupleDeclarationExpr(), |_, v| Ok(Expr::TupleDeclaration(v)))
+        } else {
+            MatchOption::new(getMatchFrom(format!("NOP"), |pos| Err(ASTError::MatchFailed(pos))), |pos, _: u8| Err(ASTError::MatchFailed(pos.getStartPos())))
+        },
         MatchOption::new(getMatchVariableExpr(), |_, v| Ok(Expr::Variable(v))),
         MatchOption::new(getMatchReadExpr(), |_, v| Ok(Expr::ReadExpr(v))),
         MatchOption::new(getMatchLiteralArray(), |_, v| Ok(Expr::LiteralArray(v))),
@@ -228,6 +269,11 @@ pub fn getMatchExcludingExpr(excludeOperator: bool, excludeDeclaration: bool) ->
         } else {
             let mut symbolVec = options.into_iter().map(|symbolMatch| {
                 let (range, symbol) = symbolMatch.take();
+                // operator expressions may end before the components that were matched (see getMatchSymbol)
+                let range = match &symbol {
+                    Expr::Operator(expr) => expr.getRange().to_owned(),
+                    _ => range,
+                };
                 Match::new(range, symbol.toSymbol())
             }).collect::<Vec<_>>();
             let index = resolveSymbolConflict(pos, symbolVec.iter())?;
@@ -422,6 +468,46 @@ pub fn getMatchClassDefinitionSym() -> impl MatchType<Value = ClassDefinitionSym
         });
 }
 
+pub fn getMatchEnumDefinitionSym() -> impl MatchType<Value = EnumDefinitionSym> {
+    // visibility enum name { variant, variant }
+    return getMappedMatch(
+        (
+            OptionalMatch::new(getMatchVisibility()),
+            getMatchKeyword(Keyword::Enum),
+            getMatchIdentifier(), // name
+            getMatchParenthesis(ParenthesisType::Curly, |module| {
+                fn parseVariant(module: &Rc<Module>) -> Result<ModulePos, ASTError> {
+                    let matchValue = getMatchIdentifier().getMatch(module.getModulePos(0))?;
+                    if matchValue.getRange().getEndIndex() != module.getTokenVector().len() {
+                        return Err(ASTError::ExpectedExclusive(matchValue.getRange().getEndPos(), None));
+                    }
+                    return Ok(matchValue.take().1);
+                }
+
+                return match module.getTokenVector().as_slice() {
+                    [token] => {
+                        if let TokenType::CommaList(tokens) = token.getTokenType() {
+                            let mut variantVec = Vec::new();
+                            for module in tokens {
+                                variantVec.push(parseVariant(module)?);
+                            }
+                            Ok(variantVec)
+                        } else {
+                            Ok(vec![parseVariant(module)?])
+                        }
+                    }
+                    _ => Ok(vec![parseVariant(module)?]),
+                };
+            }), // variants
+        ), |range, (visibility, _, name, variants)| Ok(EnumDefinitionSym {
+            visibility: visibility.unwrap_or(Visibility::Private),
+            range,
+            name,
+            variants,
+        }),
+    );
+}
+
 pub fn getMatchFunctionParameter() -> impl MatchType<Value = FunctionParameter> {
     // type name
     // type name = expr
@@ -520,6 +606,49 @@ pub fn getMatchIfSym() -> impl MatchType<Value = IfSym> {
         });
 }
 
+pub fn getMatchMatchSym() -> impl MatchType<Value = MatchSym> {
+    // match value { variant: symbol variant: symbol }
+    // match value { variant: symbol else: symbol }
+    return getMappedMatch(
+        (
+            getMatchKeyword(Keyword::Match), // match
+            getMatchExpr(), // value
+            getMatchParenthesis(ParenthesisType::Curly, |module| {
+                let cases = (
+                    getRepeatingMatch(0, getMappedMatch(
+                        (
+                            getMatchIdentifier(), // variant
+                            getMatchOperator(Operator::Colon),
+                            getMatchSymbol(), // symbol
+                        ), |_, (variant, _, symbol)| Ok(MatchCase {
+                            variant,
+                            symbol,
+                        }),
+                    )),
+                    OptionalMatch::new(getMappedMatch(
+                        (
+                            getMatchKeyword(Keyword::Else), // else
+                            getMatchOperator(Operator::Colon),
+                            getMatchSymbol(), // symbol
+                        ), |_, (_, _, symbol)| Ok(ElseSym {
+                            symbol,
+                        }),
+                    )),
+                ).getMatch(module.getModulePos(0))?;
+                if cases.getRange().getEndIndex() != module.getTokenVector().len() {
+                    return Err(ASTError::ExpectedExclusive(cases.getRange().getEndPos(), None));
+                }
+                return Ok(cases.take().1);
+            }), // cases
+        ), |range, (_, value, (caseVec, elseSym))| Ok(MatchSym {
+            range,
+            value,
+            caseVec,
+            elseSym: elseSym.map(|elseSym| Box::new(elseSym)),
+        }),
+    );
+}
+
 pub fn getMatchWhileSym() -> impl MatchType<Value = WhileLoop> {
     // label: while condition { symbols }
     // while condition { symbols }
@@ -541,6 +670,49 @@ pub fn getMatchWhileSym() -> impl MatchType<Value = WhileLoop> {
         });
 }
 
+pub fn getMatchLoopSym() -> impl MatchType<Value = Loop> {
+    // label: loop { symbols }
+    // loop { symbols }
+    return getMappedMatch(
+        (
+            OptionalMatch::new((getMatchIdentifier(), getMatchOperator(Operator::Colon))),
+            getMatchKeyword(Keyword::Loop), // loop
+            getMatchSymbol(), // symbol
+        ), |range, (label, _, symbol)| {
+            Ok(Loop {
+                symbol: Box::new(symbol),
+                range,
+                label: label.map(|(identifier, _)| Label {
+                    identifier,
+                }),
+            })
+        });
+}
+
+pub fn getMatchForSym() -> impl MatchType<Value = ForLoop> {
+    // label: for variable in start..end { symbols }
+    // for variable in start..end { symbols }
+    return getMappedMatch(
+        (
+            OptionalMatch::new((getMatchIdentifier(), getMatchOperator(Operator::Colon))),
+            getMatchKeyword(Keyword::For), // for
+            getMatchIdentifier(), // variable
+            getMatchKeyword(Keyword::In), // in
+            getMatchExpr(), // iterable
+            getMatchSymbol(), // symbol
+        ), |range, (label, _, variable, _, iterable, symbol)| {
+            Ok(ForLoop {
+                symbol: Box::new(symbol),
+                iterable,
+                variable,
+                range,
+                label: label.map(|(identifier, _)| Label {
+                    identifier,
+                }),
+            })
+        });
+}
+
 pub fn getMatchReturnSym() -> impl MatchType<Value = ReturnSym> {
     // return
     // return value
@@ -615,6 +787,9 @@ pub fn getMatchOperatorExpr() -> impl MatchType<Value = OperatorExpr> {
                  getMatchFrom(format!("Operator"), |pos| {
                      return if let TokenType::Operator(operator) = pos.getToken().getTokenType() {
                          Ok(Match::new(pos.getRangeWithLength(1), OperationComponent::Operator(pos.getRangeWithLength(1), *operator)))
+                     } else if let TokenType::Keyword(Keyword::As) = pos.getToken().getTokenType() {
+                         // "as" is lexed as a keyword (shared with import aliases)
+                         Ok(Match::new(pos.getRangeWithLength(1), OperationComponent::Operator(pos.getRangeWithLength(1), Operator::Cast)))
                      } else {
                          Err(ASTError::ExpectedTokenDiscriminant(pos, TokenTypeDiscriminants::Operator))
                      };
@@ -654,6 +829,31 @@ pub fn getMatchVariableDeclarationExpr() -> impl MatchType<Value = VariableDecla
     ]);
 }
 
+pub fn getMatchTupleDeclarationExpr() -> impl MatchType<Value = TupleDeclarationExpr> {
+    // let (a, b)
+    return getMappedMatch(
+        (
+            getMatchKeyword(Keyword::Let), // let
+            getMatchParenthesis(ParenthesisType::Rounded, |module| {
+                let [token] = module.getTokenVector().as_slice() else { return Err(ASTError::ExpectedTokenDiscriminant(module.getModulePos(0), TokenTypeDiscriminants::CommaList)) };
+                let TokenType::CommaList(moduleVec) = token.getTokenType() else { return Err(ASTError::ExpectedTokenDiscriminant(module.getModulePos(0), TokenTypeDiscriminants::CommaList)) };
+                let mut variableNames = Vec::new();
+                for module in moduleVec {
+                    let variableName = getMatchIdentifier().getMatch(module.getModulePos(0))?;
+                    if variableName.getRange().getEndIndex() != module.getTokenVector().len() {
+                        return Err(ASTError::ExpectedExclusive(variableName.getRange().getEndPos(), None));
+                    }
+                    variableNames.push(variableName.take().1);
+                }
+                return Ok(variableNames);
+            }), // names
+        ), |range, (_, variableNames)| Ok(TupleDeclarationExpr {
+            range,
+            variableNames,
+        }),
+    );
+}
+
 pub fn getMatchVariableExpr() -> impl MatchType<Value = VariableExpr> {
     // name
     return getMappedMatch(getMatchIdentifier(), |range, _| Ok(VariableExpr {
@@ -677,15 +877,14 @@ pub fn getMatchReadExpr() -> impl MatchType<Value = ReadExpr> {
 
 pub fn getMatchLiteralArray() -> impl MatchType<Value = LiteralArray> {
     // [a, b, c]
-    return getMatchParenthesis(ParenthesisType::Square, |parenthesisModule| {
-        getMatchExprCommaList().getMatch(parenthesisModule.getModulePos(0)).map(|matchValue| {
-            let (range, exprVec) = matchValue.take();
-            return LiteralArray {
-                range,
-                exprVec,
-            };
-        })
-    });
+    // range is taken from the parenthesis token, rather than the contents of the parenthesis module
+    return getMappedMatch(
+        getMatchParenthesis(ParenthesisType::Square, |parenthesisModule| getMatchExprCommaList().getMatch(parenthesisModule.getModulePos(0))),
+        |range, exprVec| Ok(LiteralArray {
+            range,
+            exprVec: exprVec.take().1,
+        }),
+    );
 }
 
 pub fn getMatchLiteralBool() -> impl MatchType<Value = LiteralBool> {
@@ -763,13 +962,12 @@ pub fn getMatchLiteralString() -> impl MatchType<Value = LiteralString> {
 
 pub fn getMatchLiteralTuple() -> impl MatchType<Value = LiteralTuple> {
     // (a, b, c)
-    return getMatchParenthesis(ParenthesisType::Rounded, |module| {
-        getMatchExprCommaList().getMatch(module.getModulePos(0)).map(|matchValue| {
-            let (range, exprVec) = matchValue.take();
-            return LiteralTuple {
-                range,
-                exprVec,
-            };
-        })
-    });
+    // (a) is also matched, and resolved as a parenthesized expression
+    return getMappedMatch(
+        getMatchParenthesis(ParenthesisType::Rounded, |module| getMatchExprCommaList().getMatch(module.getModulePos(0))),
+        |range, exprVec| Ok(LiteralTuple {
+            range,
+            exprVec: exprVec.take().1,
+        }),
+    );
 }
@@ -1,11 +1,114 @@
+use std::cmp::max;
 use std::rc::Rc;
 
 use crate::ast::ASTError;
 use crate::ast::symbol::Symbol;
-use crate::ast::tokensource::matchers::getMatchSymbolsAll;
+use crate::ast::tokensource::matchers::getMatchSymbol;
 use crate::ast::tokensource::matchtype::MatchType;
-use crate::module::Module;
+use crate::module::{Module, ParenthesisType, TokenType};
 
-pub fn parseTokenVec(module: Rc<Module>) -> Result<Vec<Symbol>, ASTError> {
-    return getMatchSymbolsAll().getMatch(module.getModulePos(0)).map(|matchValue| matchValue.take().1);
+// index of the token in the module which contains the error (the error may be within a parenthesis)
+fn getErrorIndex(module: &Module, error: &ASTError) -> usize {
+    let errorStart = error.getModulePos().getFileRange().getStartIndex();
+    return module.getTokenVector().iter().rposition(|token| token.getSourceRange().getStartIndex() <= errorStart).unwrap_or(0);
+}
+
+// index of the token at which parsing should resume after an error in the token at errorIndex
+// tokens are skipped until the next line, or until after a semicolon or closing curly parenthesis
+fn getRecoveryIndex(module: &Module, errorIndex: usize) -> usize {
+    let tokenVec = module.getTokenVector();
+    let mut index = errorIndex;
+    while index < tokenVec.len() {
+        let token = &tokenVec[index];
+        index += 1;
+        // a curly parenthesis token contains the entire balanced group (unmatched parenthesis are rejected by the tokenizer),
+        // so the group is skipped as a whole, and parsing resumes after the closing curly parenthesis
+        if let TokenType::SemiColan | TokenType::Parenthesis(ParenthesisType::Curly, _) = token.getTokenType() {
+            break;
+        }
+        if let Some(next) = tokenVec.get(index) {
+            let source = token.getSourceRange().getStart().getSourceFile().getSource();
+            if source[token.getSourceRange().getEndIndex()..next.getSourceRange().getStartIndex()].contains('\n') {
+                break;
+            }
+        }
+    }
+    return index;
+}
+
+// parsing continues after a symbol fails to match, so that all errors are reported
+// the returned symbols exclude any symbols which failed to match
+pub fn parseTokenVec(module: Rc<Module>) -> (Vec<Symbol>, Vec<ASTError>) {
+    let matchSymbol = getMatchSymbol();
+    let mut symbolVec = Vec::new();
+    let mut errorVec = Vec::new();
+    let mut index = 0;
+    while index < module.getTokenVector().len() {
+        match matchSymbol.getMatch(module.getModulePos(index)) {
+            Ok(matchValue) => {
+                debug_assert_ne!(index, matchValue.getRange().getEndIndex(), "zero length symbol matched");
+                index = matchValue.getRange().getEndIndex();
+                symbolVec.push(matchValue.take().1);
+            }
+            Err(error) => {
+                // resume after the position of the error rather than the start of the symbol, so that one error is not reported again
+                index = getRecoveryIndex(&module, max(index, getErrorIndex(&module, &error)));
+                errorVec.push(error);
+            }
+        }
+    }
+    return (symbolVec, errorVec);
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::ast::tokensource::tokenparser::parseTokenVec;
+    use crate::module::{Module, SourceFile};
+
+    // source of each parsed symbol, and position of each error
+    fn parseSource(source: &str) -> (Vec<String>, Vec<String>) {
+        let module = Module::new(SourceFile::fromSource(PathBuf::from("test.txt"), source.to_owned())).unwrap_or_else(|error| panic!("{}", error.getDiagnostic().render()));
+        let (symbolVec, errorVec) = parseTokenVec(module);
+        return (
+            symbolVec.iter().map(|symbol| symbol.getSymbolType().getRange().getFileRange().getSourceInRange().to_owned()).collect(),
+            errorVec.iter().map(|error| error.getModulePos().getFileRange().to_string()).collect(),
+        );
+    }
+
+    #[test]
+    fn testRecoverNextLine() {
+        let (symbolVec, errorVec) = parseSource("int a = 1\n= = 2\nprint(a)\nprint print\nint b = 4\n");
+        assert_eq!(vec!["int a = 1", "print(a)", "int b = 4"], symbolVec);
+        assert_eq!(vec!["test.txt:2:1", "test.txt:4:1"], errorVec);
+    }
+
+    #[test]
+    fn testRecoverSemicolonAndCurly() {
+        // parsing resumes after a semicolon or a curly parenthesis on the same line
+        let (symbolVec, errorVec) = parseSource("= 1; int a = 2\n{ int b = } int c = 3\n");
+        assert_eq!(vec!["int a = 2", "int c = 3"], symbolVec);
+        assert_eq!(vec!["test.txt:1:1", "test.txt:2:1"], errorVec);
+    }
+
+    #[test]
+    fn testRecoverBody() {
+        // an error within a body is reported once, and parsing resumes after the closing curly parenthesis
+        let (symbolVec, errorVec) = parseSource("\
+int f() {
+    int a = 1
+    = = 2
+    return a
+}
+class A {
+    int x = 1
+    = = 2
+    int y = 2
+}
+print(3)
+");
+        assert_eq!(vec!["test.txt:1:9", "test.txt:6:1"], errorVec, "{symbolVec:?}");
+        assert_eq!(Some("print(3)"), symbolVec.last().map(|symbol| symbol.as_str()));
+    }
 }
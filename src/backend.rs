@@ -6,19 +6,20 @@ use std::sync::Arc;
 use hashbrown::HashMap;
 use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyModule};
 use llvm_sys::bit_writer::LLVMWriteBitcodeToFile;
-use llvm_sys::core::{LLVMConstNull, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMCreatePassManager, LLVMDisposeBuilder, LLVMDisposeModule, LLVMInt8TypeInContext, LLVMModuleCreateWithNameInContext, LLVMRunPassManager, LLVMSetTarget};
+use llvm_sys::core::{LLVMAddFunction, LLVMAppendBasicBlockInContext, LLVMBuildCall2, LLVMBuildRet, LLVMConstInt, LLVMConstNull, LLVMContextCreate, LLVMContextDispose, LLVMCreateBuilderInContext, LLVMDisposeBuilder, LLVMDisposeMessage, LLVMDisposeModule, LLVMFunctionType, LLVMGetNamedFunction, LLVMGlobalGetValueType, LLVMInt32TypeInContext, LLVMInt8TypeInContext, LLVMModuleCreateWithNameInContext, LLVMPositionBuilderAtEnd, LLVMPrintModuleToFile, LLVMSetTarget};
+use llvm_sys::error::{LLVMDisposeErrorMessage, LLVMGetErrorMessage};
 use llvm_sys::linker::LLVMLinkModules2;
 use llvm_sys::prelude::{LLVMBasicBlockRef, LLVMBuilderRef, LLVMContextRef, LLVMModuleRef, LLVMTypeRef, LLVMValueRef};
-use llvm_sys::target::{LLVM_InitializeAllAsmParsers, LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos, LLVM_InitializeAllTargetMCs, LLVM_InitializeAllTargets, LLVMSetModuleDataLayout};
-use llvm_sys::target_machine::{LLVMCodeGenOptLevel, LLVMCodeModel, LLVMCreateTargetDataLayout, LLVMCreateTargetMachine, LLVMGetDefaultTargetTriple, LLVMGetTargetFromTriple, LLVMRelocMode};
-use llvm_sys::transforms::ipo::{LLVMAddConstantMergePass, LLVMAddDeadArgEliminationPass, LLVMAddFunctionInliningPass, LLVMAddGlobalDCEPass, LLVMAddGlobalOptimizerPass, LLVMAddStripDeadPrototypesPass};
-use llvm_sys::transforms::scalar::{LLVMAddAggressiveDCEPass, LLVMAddDeadStoreEliminationPass, LLVMAddIndVarSimplifyPass, LLVMAddLoopDeletionPass, LLVMAddLoopIdiomPass, LLVMAddLoopUnrollAndJamPass, LLVMAddMemCpyOptPass, LLVMAddReassociatePass};
-use llvm_sys::transforms::util::LLVMAddPromoteMemoryToRegisterPass;
+use llvm_sys::target::{LLVM_InitializeAllAsmParsers, LLVM_InitializeAllAsmPrinters, LLVM_InitializeAllTargetInfos, LLVM_InitializeAllTargetMCs, LLVM_InitializeAllTargets, LLVMDisposeTargetData, LLVMSetModuleDataLayout};
+use llvm_sys::target_machine::{LLVMCodeGenFileType, LLVMCodeModel, LLVMCreateTargetDataLayout, LLVMCreateTargetMachine, LLVMDisposeTargetMachine, LLVMGetHostCPUFeatures, LLVMGetHostCPUName, LLVMGetTargetFromTriple, LLVMRelocMode, LLVMTargetMachineEmitToFile, LLVMTargetMachineRef};
+use llvm_sys::transforms::pass_builder::{LLVMCreatePassBuilderOptions, LLVMDisposePassBuilderOptions, LLVMRunPasses};
 use parking_lot::Mutex;
 
 use crate::ast::visibility::Visibility;
+use crate::backend::backendoptions::BackendOptions;
 use crate::backend::emit::emit;
-use crate::backend::link::linkExecutable;
+use crate::backend::link::{LinkerFlavor, linkExecutable};
+use crate::compiler::CompilerError;
 use crate::resolver::function::Function;
 use crate::resolver::resolvedast::ResolvedAST;
 use crate::resolver::resolvedast::resolvedfunctiondefinition::ResolvedFunctionDefinition;
@@ -28,6 +29,7 @@ use crate::resolver::typeinfo::void::VOID_TYPE;
 
 pub mod emit;
 pub mod link;
+pub mod backendoptions;
 
 struct SharedContext {
     context: LLVMContextRef,
@@ -69,11 +71,16 @@ impl Context {
 pub struct CompiledModule {
     entryName: String,
     context: Context,
+    options: BackendOptions,
     module: LLVMModuleRef,
     builder: LLVMBuilderRef,
     blockStack: Vec<LLVMBasicBlockRef>,
     variableMap: HashMap<usize, LLVMValueRef>,
     functionMap: HashMap<usize, (LLVMValueRef, LLVMTypeRef)>,
+    // loop id -> (continue block, break block)
+    loopMap: HashMap<usize, (LLVMBasicBlockRef, LLVMBasicBlockRef)>,
+    // source file path -> global string (used in runtime panic messages)
+    fileNameMap: HashMap<String, LLVMValueRef>,
 }
 
 unsafe impl Send for CompiledModule {}
@@ -90,8 +97,8 @@ impl Drop for CompiledModule {
 }
 
 impl CompiledModule {
-    pub fn new(context: Context, resolved: ResolvedAST) -> Self {
-        let mut module = Self::empty(context);
+    pub fn new(context: Context, options: BackendOptions, resolved: ResolvedAST) -> Self {
+        let mut module = Self::empty(context, options);
         module.entryName = format!("main_{}", resolved.getId());
         let statementVec = resolved.take().statementVec;
         let mainFunction = ResolvedFunctionDefinition {
@@ -112,7 +119,7 @@ impl CompiledModule {
         return module;
     }
 
-    pub fn empty(context: Context) -> Self {
+    pub fn empty(context: Context, options: BackendOptions) -> Self {
         unsafe {
             let llvmContext = context.0.lock_arc();
             let builder = LLVMCreateBuilderInContext(llvmContext.context);
@@ -122,11 +129,14 @@ impl CompiledModule {
             return CompiledModule {
                 entryName: String::new(),
                 context,
+                options,
                 module,
                 builder,
                 blockStack: Vec::new(),
                 variableMap: HashMap::new(),
                 functionMap: HashMap::new(),
+                loopMap: HashMap::new(),
+                fileNameMap: HashMap::new(),
             };
         }
     }
@@ -142,8 +152,7 @@ impl CompiledModule {
         other.module = null_mut();
     }
 
-    pub fn writeBitcode(&self, path: impl AsRef<Path>) {
-        let cstring = CString::new(path.as_ref().to_str().expect("invalid string")).expect("invalid string");
+    fn createTargetMachine(&self) -> Result<LLVMTargetMachineRef, CompilerError> {
         unsafe {
             // safe to call multiple times
             LLVM_InitializeAllTargetInfos();
@@ -152,49 +161,147 @@ impl CompiledModule {
             LLVM_InitializeAllAsmParsers();
             LLVM_InitializeAllAsmPrinters();
 
-            let triple = LLVMGetDefaultTargetTriple();
+            let triple = CString::new(self.options.getTargetTriple()).unwrap();
             let mut target = null_mut();
             let mut str = null_mut();
-            if LLVMGetTargetFromTriple(triple, &mut target as *mut _, &mut str as *mut _) != 0 {
-                panic!("failed to get target from triple: {}", CStr::from_ptr(str).to_str().unwrap());
+            if LLVMGetTargetFromTriple(triple.as_ptr(), &mut target as *mut _, &mut str as *mut _) != 0 {
+                let message = CStr::from_ptr(str).to_string_lossy().into_owned();
+                LLVMDisposeMessage(str);
+                return Err(CompilerError::EmitError(format!("failed to get target from triple {triple:?}: {message}")));
             }
-            let cpu = CString::new("x86-64").unwrap();
-            let features = CString::new("").unwrap();
-            let targetMachine = LLVMCreateTargetMachine(target, triple, cpu.as_ptr(), features.as_ptr(), LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault, LLVMRelocMode::LLVMRelocDefault, LLVMCodeModel::LLVMCodeModelDefault);
+            let (cpu, features) = if self.options.targetCpu == "native" {
+                if self.options.isCrossCompiling() {
+                    return Err(CompilerError::EmitError(format!("target cpu \"native\" cannot be used when cross compiling (target {})", self.options.getTargetTriple())));
+                }
+                let cpu = LLVMGetHostCPUName();
+                let features = LLVMGetHostCPUFeatures();
+                let mut hostFeatures = CStr::from_ptr(features).to_string_lossy().into_owned();
+                // features provided by the user take precedence over host features
+                if !self.options.targetFeatures.is_empty() {
+                    hostFeatures += ",";
+                    hostFeatures += &self.options.targetFeatures;
+                }
+                let hostCpu = (CStr::from_ptr(cpu).to_owned(), CString::new(hostFeatures).unwrap());
+                LLVMDisposeMessage(cpu);
+                LLVMDisposeMessage(features);
+                hostCpu
+            } else {
+                (CString::new(self.options.targetCpu.to_owned()).unwrap(), CString::new(self.options.targetFeatures.to_owned()).unwrap())
+            };
+            // position independent code can be linked into both PIE and non-PIE executables
+            let targetMachine = LLVMCreateTargetMachine(target, triple.as_ptr(), cpu.as_ptr(), features.as_ptr(), self.options.optimizationLevel.getCodeGenLevel(), LLVMRelocMode::LLVMRelocPIC, LLVMCodeModel::LLVMCodeModelDefault);
             let dataLayout = LLVMCreateTargetDataLayout(targetMachine);
 
+            // module keeps a copy of the data layout
             LLVMSetModuleDataLayout(self.module, dataLayout);
-            LLVMSetTarget(self.module, triple);
-
-            let pass = LLVMCreatePassManager();
-            // some passes to optimize IR
-            // (by no means exhaustive, in reasonable order, or without overlap)
-            LLVMAddReassociatePass(pass);
-            LLVMAddConstantMergePass(pass);
-            LLVMAddFunctionInliningPass(pass);
-            LLVMAddIndVarSimplifyPass(pass);
-            LLVMAddLoopUnrollAndJamPass(pass);
-            LLVMAddLoopIdiomPass(pass);
-            LLVMAddLoopDeletionPass(pass);
-            LLVMAddGlobalOptimizerPass(pass);
-            LLVMAddGlobalDCEPass(pass);
-            LLVMAddDeadArgEliminationPass(pass);
-            LLVMAddMemCpyOptPass(pass);
-            LLVMAddDeadStoreEliminationPass(pass);
-            LLVMAddPromoteMemoryToRegisterPass(pass);
-            LLVMAddStripDeadPrototypesPass(pass);
-            LLVMAddAggressiveDCEPass(pass);
-            LLVMRunPassManager(pass, self.module);
-
-            // println!("{}", CStr::from_ptr(LLVMPrintModuleToString(self.module)).to_str().unwrap());
+            LLVMDisposeTargetData(dataLayout);
+            LLVMSetTarget(self.module, triple.as_ptr());
+            return Ok(targetMachine);
+        }
+    }
+
+    fn optimize(&self, targetMachine: LLVMTargetMachineRef) -> Result<(), CompilerError> {
+        let passes = CString::new(self.options.optimizationLevel.getPassPipeline()).unwrap();
+        unsafe {
+            let passOptions = LLVMCreatePassBuilderOptions();
+            let error = LLVMRunPasses(self.module, passes.as_ptr(), targetMachine, passOptions);
+            LLVMDisposePassBuilderOptions(passOptions);
+            if error != null_mut() {
+                let message = LLVMGetErrorMessage(error);
+                let errorMessage = CStr::from_ptr(message).to_string_lossy().into_owned();
+                LLVMDisposeErrorMessage(message);
+                return Err(CompilerError::EmitError(format!("failed to run passes: {errorMessage}")));
+            }
+        }
+        return Ok(());
+    }
 
+    // sets module target and optimizes module
+    fn prepare(&self) -> Result<LLVMTargetMachineRef, CompilerError> {
+        let targetMachine = self.createTargetMachine()?;
+        if let Err(error) = self.optimize(targetMachine) {
+            unsafe {
+                LLVMDisposeTargetMachine(targetMachine);
+            }
+            return Err(error);
+        }
+        return Ok(targetMachine);
+    }
+
+    pub fn writeBitcode(&self, path: impl AsRef<Path>) -> Result<(), CompilerError> {
+        let cstring = CString::new(path.as_ref().to_str().expect("invalid string")).expect("invalid string");
+        unsafe {
+            LLVMDisposeTargetMachine(self.prepare()?);
             LLVMWriteBitcodeToFile(self.module, cstring.as_ptr());
         }
+        return Ok(());
+    }
+
+    pub fn writeIR(&self, path: impl AsRef<Path>) -> Result<(), CompilerError> {
+        let cstring = CString::new(path.as_ref().to_str().expect("invalid string")).expect("invalid string");
+        unsafe {
+            LLVMDisposeTargetMachine(self.prepare()?);
+            let mut error = null_mut();
+            if LLVMPrintModuleToFile(self.module, cstring.as_ptr(), &mut error as *mut _) != 0 {
+                panic!("failed to write IR: {}", CStr::from_ptr(error).to_str().unwrap());
+            }
+        }
+        return Ok(());
+    }
+
+    fn writeTargetFile(&self, path: impl AsRef<Path>, fileType: LLVMCodeGenFileType) -> Result<(), CompilerError> {
+        let cstring = CString::new(path.as_ref().to_str().expect("invalid string")).expect("invalid string");
+        unsafe {
+            let targetMachine = self.prepare()?;
+            let mut error = null_mut();
+            let failed = LLVMTargetMachineEmitToFile(targetMachine, self.module, cstring.as_ptr() as *mut _, fileType, &mut error as *mut _) != 0;
+            LLVMDisposeTargetMachine(targetMachine);
+            if failed {
+                let message = CStr::from_ptr(error).to_string_lossy().into_owned();
+                LLVMDisposeMessage(error);
+                return Err(CompilerError::EmitError(message));
+            }
+        }
+        return Ok(());
+    }
+
+    pub fn writeAssembly(&self, path: impl AsRef<Path>) -> Result<(), CompilerError> {
+        return self.writeTargetFile(path, LLVMCodeGenFileType::LLVMAssemblyFile);
     }
 
-    pub fn writeExecutable(&self, path: impl AsRef<Path>) {
-        let bitcodePath = path.as_ref().to_str().expect("invalid string").to_owned() + ".bc";
-        self.writeBitcode(&bitcodePath);
-        linkExecutable(&self.entryName, bitcodePath, path);
+    pub fn writeObject(&self, path: impl AsRef<Path>) -> Result<(), CompilerError> {
+        return self.writeTargetFile(path, LLVMCodeGenFileType::LLVMObjectFile);
+    }
+
+    // main function calling entry, for platforms where the C runtime is responsible for program startup
+    fn emitMainFunction(&self) {
+        unsafe {
+            let name = CString::new("main").unwrap();
+            // user functions cannot be named main (see ResolutionError::ReservedFunctionName)
+            debug_assert_eq!(LLVMGetNamedFunction(self.module, name.as_ptr()), null_mut());
+
+            let context = self.context.0.lock_arc().context;
+            let intType = LLVMInt32TypeInContext(context);
+            let mainFunction = LLVMAddFunction(self.module, name.as_ptr(), LLVMFunctionType(intType, null_mut(), 0, 0));
+            let blockName = CString::new("entry").unwrap();
+            LLVMPositionBuilderAtEnd(self.builder, LLVMAppendBasicBlockInContext(context, mainFunction, blockName.as_ptr()));
+
+            let entryName = CString::new(self.entryName.to_owned()).unwrap();
+            let entryFunction = LLVMGetNamedFunction(self.module, entryName.as_ptr());
+            debug_assert_ne!(entryFunction, null_mut());
+            let valueName = CString::new("").unwrap();
+            LLVMBuildCall2(self.builder, LLVMGlobalGetValueType(entryFunction), entryFunction, null_mut(), 0, valueName.as_ptr());
+            LLVMBuildRet(self.builder, LLVMConstInt(intType, 0, 0));
+        }
+    }
+
+    pub fn writeExecutable(&self, path: impl AsRef<Path>) -> Result<(), CompilerError> {
+        let flavor = LinkerFlavor::new(&self.options.getTargetTriple());
+        if flavor.requiresMainFunction() {
+            self.emitMainFunction();
+        }
+        let objectPath = path.as_ref().to_str().expect("invalid string").to_owned() + flavor.getObjectExtension();
+        self.writeObject(&objectPath)?;
+        return linkExecutable(&self.entryName, &self.options, objectPath, path);
     }
 }
@@ -0,0 +1,88 @@
+use std::ffi::CStr;
+
+use llvm_sys::core::LLVMDisposeMessage;
+use llvm_sys::target_machine::{LLVMCodeGenOptLevel, LLVMGetDefaultTargetTriple};
+use strum_macros::EnumString;
+
+#[derive(EnumString)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum OptimizationLevel {
+    O0,
+    O1,
+    O2,
+    O3,
+    Os,
+}
+
+impl OptimizationLevel {
+    pub fn getPassPipeline(&self) -> &'static str {
+        return match self {
+            OptimizationLevel::O0 => "default<O0>",
+            OptimizationLevel::O1 => "default<O1>",
+            OptimizationLevel::O2 => "default<O2>",
+            OptimizationLevel::O3 => "default<O3>",
+            OptimizationLevel::Os => "default<Os>",
+        };
+    }
+
+    pub fn getCodeGenLevel(&self) -> LLVMCodeGenOptLevel {
+        return match self {
+            OptimizationLevel::O0 => LLVMCodeGenOptLevel::LLVMCodeGenLevelNone,
+            OptimizationLevel::O1 => LLVMCodeGenOptLevel::LLVMCodeGenLevelLess,
+            OptimizationLevel::O2 | OptimizationLevel::Os => LLVMCodeGenOptLevel::LLVMCodeGenLevelDefault,
+            OptimizationLevel::O3 => LLVMCodeGenOptLevel::LLVMCodeGenLevelAggressive,
+        };
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct BackendOptions {
+    pub optimizationLevel: OptimizationLevel,
+    // defaults to the triple of the host
+    pub targetTriple: Option<String>,
+    // "native" uses the cpu (and features) of the host
+    pub targetCpu: String,
+    pub targetFeatures: String,
+    // integer division and remainder trap if the divisor is zero or the division overflows (otherwise the result is undefined)
+    pub divisionByZeroTrap: bool,
+    // array and string indices are checked against the length (otherwise out of bounds access is undefined)
+    pub boundsChecks: bool,
+    // program used to link executables, rather than the default for the target (see LinkerFlavor::getLinkerPath)
+    pub linker: Option<String>,
+}
+
+impl Default for BackendOptions {
+    fn default() -> Self {
+        return Self {
+            optimizationLevel: OptimizationLevel::O0,
+            targetTriple: None,
+            targetCpu: "generic".to_owned(),
+            targetFeatures: String::new(),
+            divisionByZeroTrap: false,
+            boundsChecks: true,
+            linker: None,
+        };
+    }
+}
+
+impl BackendOptions {
+    fn getDefaultTargetTriple() -> String {
+        unsafe {
+            let triple = LLVMGetDefaultTargetTriple();
+            let defaultTriple = CStr::from_ptr(triple).to_string_lossy().into_owned();
+            LLVMDisposeMessage(triple);
+            return defaultTriple;
+        }
+    }
+
+    pub fn getTargetTriple(&self) -> String {
+        if let Some(triple) = &self.targetTriple {
+            return triple.to_owned();
+        }
+        return Self::getDefaultTargetTriple();
+    }
+
+    pub fn isCrossCompiling(&self) -> bool {
+        return self.targetTriple.as_ref().is_some_and(|triple| *triple != Self::getDefaultTargetTriple());
+    }
+}
@@ -2,23 +2,26 @@ use std::ffi::CString;
 
 use hashbrown::hash_map::Entry;
 use llvm_sys::analysis::{LLVMVerifierFailureAction, LLVMVerifyFunction};
-use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMAppendBasicBlockInContext, LLVMBasicBlockAsValue, LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr, LLVMBuildExactSDiv, LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFSub, LLVMBuildGlobalString, LLVMBuildICmp, LLVMBuildLoad2, LLVMBuildMul, LLVMBuildNot, LLVMBuildOr, LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildSRem, LLVMBuildStore, LLVMBuildStructGEP2, LLVMBuildSub, LLVMConstInt, LLVMConstNull, LLVMConstPointerNull, LLVMConstReal, LLVMConstStructInContext, LLVMFloatTypeInContext, LLVMFunctionType, LLVMGetInsertBlock, LLVMGetParam, LLVMInsertBasicBlockInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt8TypeInContext, LLVMIsNull, LLVMPositionBuilderAtEnd, LLVMSetInitializer};
-use llvm_sys::LLVMIntPredicate;
+use llvm_sys::core::{LLVMAddFunction, LLVMAddGlobal, LLVMAddCase, LLVMAddIncoming, LLVMAppendBasicBlockInContext, LLVMArrayType, LLVMBasicBlockAsValue, LLVMBuildAdd, LLVMBuildAlloca, LLVMBuildAnd, LLVMBuildBr, LLVMBuildCall2, LLVMBuildCondBr, LLVMBuildExtractValue, LLVMBuildFAdd, LLVMBuildFDiv, LLVMBuildFMul, LLVMBuildFNeg, LLVMBuildFCmp, LLVMBuildFPToSI, LLVMBuildFRem, LLVMBuildFSub, LLVMBuildGlobalString, LLVMBuildICmp, LLVMBuildInBoundsGEP2, LLVMBuildInsertValue, LLVMBuildLoad2, LLVMBuildMul, LLVMBuildNeg, LLVMBuildNot, LLVMBuildOr, LLVMBuildPhi, LLVMBuildRet, LLVMBuildRetVoid, LLVMBuildSDiv, LLVMBuildSIToFP, LLVMBuildSRem, LLVMBuildStore, LLVMBuildStructGEP2, LLVMBuildSub, LLVMBuildSwitch, LLVMBuildTrunc, LLVMBuildUnreachable, LLVMBuildZExt, LLVMConstAllOnes, LLVMConstArray, LLVMConstInt, LLVMConstNull, LLVMConstPointerNull, LLVMConstReal, LLVMConstStructInContext, LLVMFloatTypeInContext, LLVMFunctionType, LLVMGetBasicBlockParent, LLVMGetBasicBlockTerminator, LLVMGetInsertBlock, LLVMGetElementType, LLVMGetNamedGlobal, LLVMGetParam, LLVMGetUndef, LLVMGlobalGetValueType, LLVMInsertBasicBlockInContext, LLVMInt1TypeInContext, LLVMInt32TypeInContext, LLVMInt8TypeInContext, LLVMIsConstant, LLVMIsNull, LLVMMoveBasicBlockAfter, LLVMPositionBuilderAtEnd, LLVMSetGlobalConstant, LLVMSetInitializer, LLVMTypeOf};
+use llvm_sys::{LLVMIntPredicate, LLVMRealPredicate};
 use llvm_sys::prelude::{LLVMBasicBlockRef, LLVMBool, LLVMContextRef, LLVMTypeRef, LLVMValueRef};
 use once_cell::sync::Lazy;
 
 use crate::ast::visibility::Visibility;
 use crate::backend::CompiledModule;
-use crate::module::Operator;
+use crate::module::{FileRange, Operator};
 use crate::resolver::function::{Function, Parameter};
 use crate::resolver::resolvedast::defaultvalue::DefaultValue;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::resolvedast::resolvedindex::ResolvedIndex;
 use crate::resolver::resolvedast::resolvedoperator::ResolvedOperator;
 use crate::resolver::resolvedast::resolvedscope::ResolvedScope;
 use crate::resolver::resolvedast::resolvedvariable::ResolvedVariable;
 use crate::resolver::resolvedast::statement::Statement;
 use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+use crate::resolver::resolvedast::variableinitialize::VariableInitialize;
 use crate::resolver::typeinfo::pointer::PointerType;
+use crate::resolver::typeinfo::primitive::boolean::BOOLEAN_TYPE;
 use crate::resolver::typeinfo::primitive::character::CHARACTER_TYPE;
 use crate::resolver::typeinfo::primitive::float::FLOAT_TYPE;
 use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
@@ -45,7 +48,87 @@ unsafe fn emitOperatorAssign(module: &mut CompiledModule, mut operands: Vec<Reso
     return LLVMBuildStore(module.builder, modifiedValue, variable);
 }
 
-unsafe fn basicOperator(module: &mut CompiledModule, operator: Operator, exprType: Type, operands: Vec<LLVMValueRef>) -> LLVMValueRef {
+// value of a postfix operator is the value before it was modified, prefix operators provide the modified value
+unsafe fn emitIncrement(module: &mut CompiledModule, variable: ResolvedExpr, operator: Operator) -> LLVMValueRef {
+    let expressionType = variable.getExpressionType();
+    let contextLock = module.context.0.lock_arc();
+    let context = contextLock.context;
+    let llvmExpressionType = expressionType.getLLVMType(context);
+    let one = if expressionType == FLOAT_TYPE {
+        LLVMConstReal(llvmExpressionType, 1.0)
+    } else {
+        LLVMConstInt(llvmExpressionType, 1, false as LLVMBool)
+    };
+    drop(contextLock);
+
+    let name = CString::new("value").unwrap();
+    let variable = getAssignValue(module, variable);
+    let value = LLVMBuildLoad2(module.builder, llvmExpressionType, variable, name.as_ptr());
+
+    let modifiedValue = match operator {
+        Operator::Increment | Operator::PrefixIncrement => basicOperator(module, Operator::Plus, expressionType, vec![value, one]),
+        Operator::Decrement | Operator::PrefixDecrement => basicOperator(module, Operator::Minus, expressionType, vec![value, one]),
+        _ => unreachable!()
+    };
+    LLVMBuildStore(module.builder, modifiedValue, variable);
+
+    return if operator.isPrefix() {
+        modifiedValue
+    } else {
+        value
+    };
+}
+
+// aborts the program, terminating the current block
+unsafe fn emitTrap(module: &mut CompiledModule) {
+    static FUNCTION: Lazy<Function> = Lazy::new(|| Function::new("llvm.trap".to_string(), Visibility::Public, VOID_TYPE.to_owned(), vec![]));
+
+    let name = CString::new("").unwrap();
+    let mut operands = vec![];
+    let (trapFunction, functionType) = getFunctionValue(module, FUNCTION.to_owned());
+    LLVMBuildCall2(module.builder, functionType, trapFunction, operands.as_mut_ptr(), operands.len() as _, name.as_ptr());
+    LLVMBuildUnreachable(module.builder);
+}
+
+// traps if the divisor is zero, or if the division overflows (minimum value divided by -1), rather than leaving the result of the division undefined
+unsafe fn emitDivisionCheck(module: &mut CompiledModule, dividend: LLVMValueRef, divisor: LLVMValueRef) {
+    if !module.options.divisionByZeroTrap {
+        return;
+    }
+
+    let currentBlock = LLVMGetInsertBlock(module.builder);
+    let function = LLVMGetBasicBlockParent(currentBlock);
+    let contextLock = module.context.0.lock_arc();
+    let context = contextLock.context;
+    let name = CString::new("division_valid").unwrap();
+    let validBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(validBlock, currentBlock);
+    let name = CString::new("division_invalid").unwrap();
+    let trapBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(trapBlock, currentBlock);
+    drop(contextLock);
+
+    let integerType = LLVMTypeOf(divisor);
+    let name = CString::new("is_zero").unwrap();
+    let isZero = LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntEQ, divisor, LLVMConstNull(integerType), name.as_ptr());
+    let name = CString::new("is_negative_one").unwrap();
+    let isNegativeOne = LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntEQ, divisor, LLVMConstAllOnes(integerType), name.as_ptr());
+    let name = CString::new("is_minimum").unwrap();
+    let isMinimum = LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntEQ, dividend, LLVMConstInt(integerType, i32::MIN as _, LLVMBool::from(true)), name.as_ptr());
+    let name = CString::new("is_overflow").unwrap();
+    let isOverflow = LLVMBuildAnd(module.builder, isNegativeOne, isMinimum, name.as_ptr());
+    let name = CString::new("is_invalid").unwrap();
+    let isInvalid = LLVMBuildOr(module.builder, isZero, isOverflow, name.as_ptr());
+    LLVMBuildCondBr(module.builder, isInvalid, trapBlock, validBlock);
+
+    LLVMPositionBuilderAtEnd(module.builder, trapBlock);
+    emitTrap(module);
+
+    LLVMPositionBuilderAtEnd(module.builder, validBlock);
+}
+
+// operandType is the type of the operands (rather than the result, which is bool for comparisons)
+unsafe fn basicOperator(module: &mut CompiledModule, operator: Operator, operandType: Type, operands: Vec<LLVMValueRef>) -> LLVMValueRef {
     let name = CString::new(format!("operator_{:?}", operator)).unwrap();
     let name = name.as_ptr();
 
@@ -53,80 +136,253 @@ unsafe fn basicOperator(module: &mut CompiledModule, operator: Operator, exprTyp
         Operator::Not => {
             LLVMBuildNot(module.builder, operands[0], name)
         }
+        Operator::Negate => {
+            if operandType == INTEGER_TYPE {
+                LLVMBuildNeg(module.builder, operands[0], name)
+            } else if operandType == FLOAT_TYPE {
+                LLVMBuildFNeg(module.builder, operands[0], name)
+            } else {
+                panic!("unknown arithmetic type {:?}", operandType);
+            }
+        }
         Operator::Plus => {
-            if exprType == INTEGER_TYPE {
+            if operandType == INTEGER_TYPE {
                 LLVMBuildAdd(module.builder, operands[0], operands[1], name)
-            } else if exprType == FLOAT_TYPE {
+            } else if operandType == FLOAT_TYPE {
                 LLVMBuildFAdd(module.builder, operands[0], operands[1], name)
             } else {
-                panic!("unknown arithmetic type {:?}", exprType);
+                panic!("unknown arithmetic type {:?}", operandType);
             }
         }
         Operator::Minus => {
-            if exprType == INTEGER_TYPE {
+            if operandType == INTEGER_TYPE {
                 LLVMBuildSub(module.builder, operands[0], operands[1], name)
-            } else if exprType == FLOAT_TYPE {
+            } else if operandType == FLOAT_TYPE {
                 LLVMBuildFSub(module.builder, operands[0], operands[1], name)
             } else {
-                panic!("unknown arithmetic type {:?}", exprType);
+                panic!("unknown arithmetic type {:?}", operandType);
             }
         }
         Operator::Mult => {
-            if exprType == INTEGER_TYPE {
+            if operandType == INTEGER_TYPE {
                 LLVMBuildMul(module.builder, operands[0], operands[1], name)
-            } else if exprType == FLOAT_TYPE {
+            } else if operandType == FLOAT_TYPE {
                 LLVMBuildFMul(module.builder, operands[0], operands[1], name)
             } else {
-                panic!("unknown arithmetic type {:?}", exprType);
+                panic!("unknown arithmetic type {:?}", operandType);
             }
         }
         Operator::Div => {
-            if exprType == INTEGER_TYPE {
-                LLVMBuildExactSDiv(module.builder, operands[0], operands[1], name)
-            } else if exprType == FLOAT_TYPE {
+            if operandType == INTEGER_TYPE {
+                emitDivisionCheck(module, operands[0], operands[1]);
+                LLVMBuildSDiv(module.builder, operands[0], operands[1], name)
+            } else if operandType == FLOAT_TYPE {
                 LLVMBuildFDiv(module.builder, operands[0], operands[1], name)
             } else {
-                panic!("unknown arithmetic type {:?}", exprType);
+                panic!("unknown arithmetic type {:?}", operandType);
             }
         }
         Operator::Mod => {
-            if exprType == INTEGER_TYPE {
+            if operandType == INTEGER_TYPE {
+                emitDivisionCheck(module, operands[0], operands[1]);
                 LLVMBuildSRem(module.builder, operands[0], operands[1], name)
+            } else if operandType == FLOAT_TYPE {
+                LLVMBuildFRem(module.builder, operands[0], operands[1], name)
             } else {
-                panic!("unknown type for mod {:?}", exprType);
+                panic!("unknown arithmetic type {:?}", operandType);
             }
         }
+        Operator::Greater | Operator::Less | Operator::GreaterEq | Operator::LessEq | Operator::CompareEq | Operator::CompareNotEq => {
+            if operandType == FLOAT_TYPE {
+                // ordered comparisons are false if either operand is NaN (!= is unordered, so that it is the negation of ==)
+                let predicate = match operator {
+                    Operator::Greater => LLVMRealPredicate::LLVMRealOGT,
+                    Operator::Less => LLVMRealPredicate::LLVMRealOLT,
+                    Operator::GreaterEq => LLVMRealPredicate::LLVMRealOGE,
+                    Operator::LessEq => LLVMRealPredicate::LLVMRealOLE,
+                    Operator::CompareEq => LLVMRealPredicate::LLVMRealOEQ,
+                    Operator::CompareNotEq => LLVMRealPredicate::LLVMRealUNE,
+                    _ => unreachable!(),
+                };
+                LLVMBuildFCmp(module.builder, predicate, operands[0], operands[1], name)
+            } else if operandType == INTEGER_TYPE || operandType == CHARACTER_TYPE || operandType == BOOLEAN_TYPE || operandType.getEnumType().is_some() {
+                // chars are compared as unsigned bytes, and false < true (enums are only compared for equality)
+                let signed = operandType == INTEGER_TYPE;
+                let predicate = match operator {
+                    Operator::Greater => if signed { LLVMIntPredicate::LLVMIntSGT } else { LLVMIntPredicate::LLVMIntUGT },
+                    Operator::Less => if signed { LLVMIntPredicate::LLVMIntSLT } else { LLVMIntPredicate::LLVMIntULT },
+                    Operator::GreaterEq => if signed { LLVMIntPredicate::LLVMIntSGE } else { LLVMIntPredicate::LLVMIntUGE },
+                    Operator::LessEq => if signed { LLVMIntPredicate::LLVMIntSLE } else { LLVMIntPredicate::LLVMIntULE },
+                    Operator::CompareEq => LLVMIntPredicate::LLVMIntEQ,
+                    Operator::CompareNotEq => LLVMIntPredicate::LLVMIntNE,
+                    _ => unreachable!(),
+                };
+                LLVMBuildICmp(module.builder, predicate, operands[0], operands[1], name)
+            } else {
+                panic!("unknown comparison type {:?}", operandType);
+            }
+        }
+        _ => unreachable!()
+    }
+}
+
+// the second operand is only evaluated if the first operand does not determine the result
+//  and: first ? second : false
+//  or: first ? true : second
+unsafe fn emitShortCircuit(module: &mut CompiledModule, operator: Operator, mut operands: Vec<ResolvedExpr>) -> LLVMValueRef {
+    let first = emitExpr(module, operands.remove(0));
+    let firstBlock = LLVMGetInsertBlock(module.builder);
+    let function = LLVMGetBasicBlockParent(firstBlock);
+
+    let contextLock = module.context.0.lock_arc();
+    let context = contextLock.context;
+    let operatorName = format!("{:?}", operator).to_lowercase();
+    let name = CString::new(format!("{operatorName}_end")).unwrap();
+    let endBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(endBlock, firstBlock);
+    let name = CString::new(format!("{operatorName}_rhs")).unwrap();
+    let secondBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(secondBlock, firstBlock);
+    let boolType = LLVMInt1TypeInContext(context);
+    drop(contextLock);
+
+    let shortCircuitValue = match operator {
         Operator::And => {
-            LLVMBuildAnd(module.builder, operands[0], operands[1], name)
+            LLVMBuildCondBr(module.builder, first, secondBlock, endBlock);
+            LLVMConstInt(boolType, 0, false as LLVMBool)
         }
         Operator::Or => {
-            LLVMBuildOr(module.builder, operands[0], operands[1], name)
-        }
-        Operator::Greater => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntSGT, operands[0], operands[1], name)
-        }
-        Operator::Less => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntSLT, operands[0], operands[1], name)
-        }
-        Operator::GreaterEq => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntSGE, operands[0], operands[1], name)
-        }
-        Operator::LessEq => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntSLE, operands[0], operands[1], name)
-        }
-        Operator::CompareEq => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntEQ, operands[0], operands[1], name)
-        }
-        Operator::CompareNotEq => {
-            LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntNE, operands[0], operands[1], name)
+            LLVMBuildCondBr(module.builder, first, endBlock, secondBlock);
+            LLVMConstInt(boolType, 1, false as LLVMBool)
         }
         _ => unreachable!()
-    }
+    };
+
+    LLVMPositionBuilderAtEnd(module.builder, secondBlock);
+    let second = emitExpr(module, operands.remove(0));
+    // second operand may have changed the current block
+    let secondBlock = LLVMGetInsertBlock(module.builder);
+    LLVMBuildBr(module.builder, endBlock);
+
+    LLVMPositionBuilderAtEnd(module.builder, endBlock);
+    let name = CString::new(format!("operator_{:?}", operator)).unwrap();
+    let phi = LLVMBuildPhi(module.builder, boolType, name.as_ptr());
+    let mut values = [shortCircuitValue, second];
+    let mut blocks = [firstBlock, secondBlock];
+    LLVMAddIncoming(phi, values.as_mut_ptr(), blocks.as_mut_ptr(), values.len() as _);
+    return phi;
 }
 
-unsafe fn basicOperatorResolved(module: &mut CompiledModule, operator: Operator, exprType: Type, operands: Vec<ResolvedExpr>) -> LLVMValueRef {
+unsafe fn basicOperatorResolved(module: &mut CompiledModule, operator: Operator, operands: Vec<ResolvedExpr>) -> LLVMValueRef {
+    let operandType = operands[0].getExpressionType();
     let operands = operands.into_iter().map(|v| emitExpr(module, v)).collect();
-    return basicOperator(module, operator, exprType, operands);
+    return basicOperator(module, operator, operandType, operands);
+}
+
+// reports the source location and exits if the index is not less than the length (negative indices compare as unsigned)
+unsafe fn emitBoundsCheck(module: &mut CompiledModule, index: LLVMValueRef, length: LLVMValueRef, range: &FileRange) {
+    if !module.options.boundsChecks {
+        return;
+    }
+
+    static FUNCTION: Lazy<Function> = Lazy::new(|| Function::new("sdk_panic_bounds".to_string(), Visibility::Public, VOID_TYPE.to_owned(), vec![
+        Parameter {
+            ty: INTEGER_TYPE.to_owned(),
+            name: "index".to_string(),
+        },
+        Parameter {
+            ty: INTEGER_TYPE.to_owned(),
+            name: "length".to_string(),
+        },
+        Parameter {
+            ty: PointerType::new(CHARACTER_TYPE.to_owned()),
+            name: "file".to_string(),
+        },
+        Parameter {
+            ty: INTEGER_TYPE.to_owned(),
+            name: "line".to_string(),
+        },
+    ]));
+
+    let currentBlock = LLVMGetInsertBlock(module.builder);
+    let function = LLVMGetBasicBlockParent(currentBlock);
+    let contextLock = module.context.0.lock_arc();
+    let context = contextLock.context;
+    let name = CString::new("index_valid").unwrap();
+    let validBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(validBlock, currentBlock);
+    let name = CString::new("index_out_of_bounds").unwrap();
+    let panicBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+    LLVMMoveBasicBlockAfter(panicBlock, currentBlock);
+    drop(contextLock);
+
+    let name = CString::new("is_out_of_bounds").unwrap();
+    let isOutOfBounds = LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntUGE, index, length, name.as_ptr());
+    LLVMBuildCondBr(module.builder, isOutOfBounds, panicBlock, validBlock);
+
+    LLVMPositionBuilderAtEnd(module.builder, panicBlock);
+    let (line, _) = range.getStart().getLineColumn();
+    let fileName = getFileNameGlobal(module, range);
+    let mut operands = vec![
+        index,
+        length,
+        fileName,
+        emitExpr(module, ResolvedExpr::LiteralInteger(line as _)),
+    ];
+    let name = CString::new("").unwrap();
+    let (panicFunction, functionType) = getFunctionValue(module, FUNCTION.to_owned());
+    LLVMBuildCall2(module.builder, functionType, panicFunction, operands.as_mut_ptr(), operands.len() as _, name.as_ptr());
+    LLVMBuildUnreachable(module.builder);
+
+    LLVMPositionBuilderAtEnd(module.builder, validBlock);
+}
+
+// the name of each source file is emitted once per module
+unsafe fn getFileNameGlobal(module: &mut CompiledModule, range: &FileRange) -> LLVMValueRef {
+    let fileName = range.getStart().getSourceFile().getFilePath().display().to_string();
+    return match module.fileNameMap.entry(fileName) {
+        Entry::Occupied(entry) => *entry.get(),
+        Entry::Vacant(entry) => {
+            let fileName = CString::new(entry.key().as_str()).unwrap();
+            let name = CString::new("file_name").unwrap();
+            *entry.insert(LLVMBuildGlobalString(module.builder, fileName.as_ptr(), name.as_ptr()))
+        }
+    };
+}
+
+// pointer to the indexed element, an array which is not assignable is stored in a temporary allocation
+unsafe fn getIndexPointer(module: &mut CompiledModule, expr: ResolvedIndex) -> LLVMValueRef {
+    let valueType = expr.value.getExpressionType();
+    let name = CString::new("index").unwrap();
+    return if let Some(arrayInfo) = valueType.getArrayType() {
+        let arrayType = valueType.getLLVMType(module.context.0.lock_arc().context);
+        let array = if expr.value.getResolvedExprType().isAssignable() {
+            getAssignValue(module, expr.value)
+        } else {
+            let value = emitExpr(module, expr.value);
+            let temporaryName = CString::new("array").unwrap();
+            let temporary = LLVMBuildAlloca(module.builder, arrayType, temporaryName.as_ptr());
+            LLVMBuildStore(module.builder, value, temporary);
+            temporary
+        };
+        let index = emitExpr(module, expr.index);
+        let length = emitExpr(module, ResolvedExpr::LiteralInteger(arrayInfo.length as _));
+        emitBoundsCheck(module, index, length, &expr.range);
+        let mut indices = [emitExpr(module, ResolvedExpr::LiteralInteger(0)), index];
+        LLVMBuildInBoundsGEP2(module.builder, arrayType, array, indices.as_mut_ptr(), indices.len() as _, name.as_ptr())
+    } else {
+        // string characters are accessed through the pointer property
+        let elementType = expr.ty.getLLVMType(module.context.0.lock_arc().context);
+        let string = emitExpr(module, expr.value);
+        let pointerName = CString::new("pointer_value").unwrap();
+        let pointer = LLVMBuildExtractValue(module.builder, string, STRING_TYPE.getPropertyMap().get("pointer").unwrap().index as _, pointerName.as_ptr());
+        let lengthName = CString::new("length_value").unwrap();
+        let length = LLVMBuildExtractValue(module.builder, string, STRING_TYPE.getPropertyMap().get("length").unwrap().index as _, lengthName.as_ptr());
+        let index = emitExpr(module, expr.index);
+        emitBoundsCheck(module, index, length, &expr.range);
+        let mut indices = [index];
+        LLVMBuildInBoundsGEP2(module.builder, elementType, pointer, indices.as_mut_ptr(), indices.len() as _, name.as_ptr())
+    };
 }
 
 unsafe fn getAssignValue(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMValueRef {
@@ -143,6 +399,9 @@ unsafe fn getAssignValue(module: &mut CompiledModule, expr: ResolvedExpr) -> LLV
             let exprValue = getAssignValue(module, expr.value);
             LLVMBuildStructGEP2(module.builder, exprType, exprValue, expr.property.index as _, name.as_ptr())
         }
+        ResolvedExpr::Index(expr) => {
+            getIndexPointer(module, *expr)
+        }
         expr if expr.getResolvedExprType().isAssignable() => {
             panic!("missing assignable branch");
         }
@@ -150,6 +409,22 @@ unsafe fn getAssignValue(module: &mut CompiledModule, expr: ResolvedExpr) -> LLV
     };
 }
 
+// global or stack allocation for the variable, without initializing it
+unsafe fn emitVariableAllocation(module: &mut CompiledModule, variable: &VariableDeclare) -> LLVMValueRef {
+    let value = if variable.global {
+        let name = CString::new(format!("Global_{}", variable.ty.getTypeName())).unwrap();
+        let value = LLVMAddGlobal(module.module, variable.ty.getLLVMType(module.context.0.lock_arc().context), name.as_ptr());
+        value
+    } else {
+        let name = CString::new(format!("Allocate_{}", variable.ty.getTypeName())).unwrap();
+        let alloc = LLVMBuildAlloca(module.builder, variable.ty.getLLVMType(module.context.0.lock_arc().context), name.as_ptr());
+        alloc
+    };
+    let _v = module.variableMap.insert(variable.id, value);
+    debug_assert!(_v.is_none());
+    return value;
+}
+
 pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMValueRef {
     return match expr {
         ResolvedExpr::Read(_) => {
@@ -165,29 +440,8 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
             debug_assert_eq!(operands.len(), expr.operator.getOperands());
 
             match expr.operator {
-                Operator::Increment => {
-                    if expr.expressionType == INTEGER_TYPE {
-                        debug_assert_eq!(operands.len(), 1);
-                        debug_assert!(matches!(&operands[0], ResolvedExpr::Variable(_)));
-                        emitExpr(module, ResolvedExpr::Operator(Box::new(ResolvedOperator {
-                            operator: Operator::PlusAssign,
-                            operands: Box::new([operands.remove(0), ResolvedExpr::LiteralInteger(1)]),
-                            expressionType: INTEGER_TYPE.to_owned(),
-                        })))
-                    } else {
-                        panic!("unexpected increment type {:?}", expr.expressionType)
-                    }
-                }
-                Operator::Decrement => {
-                    if expr.expressionType == INTEGER_TYPE {
-                        emitExpr(module, ResolvedExpr::Operator(Box::new(ResolvedOperator {
-                            operator: Operator::DivAssign,
-                            operands: Box::new([operands.remove(0), ResolvedExpr::LiteralInteger(1)]),
-                            expressionType: INTEGER_TYPE.to_owned(),
-                        })))
-                    } else {
-                        panic!("unexpected increment type {:?}", expr.expressionType)
-                    }
+                Operator::Increment | Operator::Decrement | Operator::PrefixIncrement | Operator::PrefixDecrement => {
+                    emitIncrement(module, operands.remove(0), expr.operator)
                 }
                 Operator::PlusAssign => {
                     emitOperatorAssign(module, operands, Operator::Plus)
@@ -204,16 +458,39 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
                 Operator::ModAssign => {
                     emitOperatorAssign(module, operands, Operator::Mod)
                 }
+                Operator::And | Operator::Or => {
+                    emitShortCircuit(module, expr.operator, operands)
+                }
                 Operator::AssignEq => {
                     let value = emitExpr(module, operands.remove(1));
                     let assignValue = getAssignValue(module, operands.remove(0));
                     LLVMBuildStore(module.builder, value, assignValue)
                 }
-                Operator::Cast | Operator::Dot | Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation => {
+                Operator::Cast | Operator::Dot | Operator::Index | Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation => {
                     // should have been previously handled/removed
                     unreachable!()
                 }
-                _ => basicOperatorResolved(module, expr.operator, expr.expressionType, operands),
+                _ => basicOperatorResolved(module, expr.operator, operands),
+            }
+        }
+        ResolvedExpr::Cast(expr) => {
+            let valueType = expr.value.getExpressionType();
+            let llvmType = expr.ty.getLLVMType(module.context.0.lock_arc().context);
+            let value = emitExpr(module, expr.value);
+            let name = CString::new(format!("cast_{}", expr.ty.getTypeName())).unwrap();
+            let name = name.as_ptr();
+            if valueType == expr.ty {
+                value
+            } else if valueType == INTEGER_TYPE && expr.ty == FLOAT_TYPE {
+                LLVMBuildSIToFP(module.builder, value, llvmType, name)
+            } else if valueType == FLOAT_TYPE && expr.ty == INTEGER_TYPE {
+                LLVMBuildFPToSI(module.builder, value, llvmType, name)
+            } else if valueType == INTEGER_TYPE && expr.ty == CHARACTER_TYPE {
+                LLVMBuildTrunc(module.builder, value, llvmType, name)
+            } else if (valueType == CHARACTER_TYPE || valueType == BOOLEAN_TYPE) && expr.ty == INTEGER_TYPE {
+                LLVMBuildZExt(module.builder, value, llvmType, name)
+            } else {
+                panic!("unexpected cast from {:?} to {:?}", valueType, expr.ty)
             }
         }
         ResolvedExpr::FunctionCall(expr) => {
@@ -230,17 +507,7 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
             LLVMBuildCall2(module.builder, functionType, function, operands.as_mut_ptr(), operands.len() as _, name.as_ptr())
         }
         ResolvedExpr::VariableDeclaration(expr) => {
-            let value = if expr.global {
-                let name = CString::new(format!("Global_{}", expr.ty.getTypeName())).unwrap();
-                let value = LLVMAddGlobal(module.module, expr.ty.getLLVMType(module.context.0.lock_arc().context), name.as_ptr());
-                value
-            } else {
-                let name = CString::new(format!("Allocate_{}", expr.ty.getTypeName())).unwrap();
-                let alloc = LLVMBuildAlloca(module.builder, expr.ty.getLLVMType(module.context.0.lock_arc().context), name.as_ptr());
-                alloc
-            };
-            let _v = module.variableMap.insert(expr.id, value);
-            debug_assert!(_v.is_none());
+            let value = emitVariableAllocation(module, &expr);
 
             let defaultValue = ResolvedExpr::DefaultValue(DefaultValue {
                 ty: expr.ty.to_owned(),
@@ -260,6 +527,47 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
 
             value
         }
+        ResolvedExpr::VariableInitialize(expr) => {
+            let expr = *expr;
+            let ty = expr.variable.ty.to_owned();
+            let global = expr.variable.global;
+            // value is emitted before the allocation, so that the variable cannot be referenced by its own initializer
+            let value = emitExpr(module, expr.value);
+            let variable = emitVariableAllocation(module, &expr.variable);
+            if global {
+                if LLVMIsConstant(value) != 0 {
+                    LLVMSetInitializer(variable, value);
+                } else {
+                    // initialized when reached in the module entry function (in source order)
+                    LLVMSetInitializer(variable, emitExpr(module, ty.getDefaultValue()));
+                    LLVMBuildStore(module.builder, value, variable);
+                }
+            } else {
+                LLVMBuildStore(module.builder, value, variable);
+            }
+            variable
+        }
+        ResolvedExpr::TupleInitialize(expr) => {
+            let expr = *expr;
+            // tuple is emitted once, and each variable is initialized with the element at its position
+            let value = emitExpr(module, expr.value);
+            for (index, variableDeclare) in expr.variableVec.iter().enumerate() {
+                let name = CString::new(format!("element_{index}")).unwrap();
+                let element = LLVMBuildExtractValue(module.builder, value, index as _, name.as_ptr());
+                let variable = emitVariableAllocation(module, variableDeclare);
+                if variableDeclare.global {
+                    if LLVMIsConstant(element) != 0 {
+                        LLVMSetInitializer(variable, element);
+                    } else {
+                        LLVMSetInitializer(variable, emitExpr(module, variableDeclare.ty.getDefaultValue()));
+                        LLVMBuildStore(module.builder, element, variable);
+                    }
+                } else {
+                    LLVMBuildStore(module.builder, element, variable);
+                }
+            }
+            value
+        }
         ResolvedExpr::Variable(expr) => {
             let name = CString::new(format!("Load_{}", expr.ty.getTypeName())).unwrap();
             LLVMBuildLoad2(module.builder, expr.ty.getLLVMType(module.context.0.lock_arc().context), *module.variableMap.get(&expr.id).unwrap(), name.as_ptr())
@@ -270,7 +578,7 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
         ResolvedExpr::DefaultClass(expr) => {
             let mut properties = expr.ty.getPropertyMap().values().collect::<Vec<_>>();
             properties.sort_by_key(|property| property.index);
-            let mut properties = properties.iter().map(|property| emitExpr(module, property.ty.getDefaultValue())).collect::<Vec<_>>();
+            let mut properties = properties.iter().map(|property| emitExpr(module, property.defaultValue.to_owned().unwrap_or_else(|| property.ty.getDefaultValue()))).collect::<Vec<_>>();
             LLVMConstStructInContext(module.context.0.lock_arc().context, properties.as_mut_ptr(), properties.len() as _, 0)
         }
         ResolvedExpr::DefaultPointer(expr) => {
@@ -280,6 +588,20 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
             let name = CString::new(format!("property_{}", expr.property.name)).unwrap();
             LLVMBuildExtractValue(module.builder, emitExpr(module, expr.value), expr.property.index as _, name.as_ptr())
         }
+        ResolvedExpr::Index(expr) => {
+            let name = CString::new(format!("Load_{}", expr.ty.getTypeName())).unwrap();
+            let elementType = expr.ty.getLLVMType(module.context.0.lock_arc().context);
+            let pointer = getIndexPointer(module, *expr);
+            LLVMBuildLoad2(module.builder, elementType, pointer, name.as_ptr())
+        }
+        ResolvedExpr::ArrayLength(expr) => {
+            // value is only emitted for its side effects
+            emitExpr(module, expr.value);
+            emitExpr(module, ResolvedExpr::LiteralInteger(expr.length as _))
+        }
+        ResolvedExpr::LiteralEnum(expr) => {
+            LLVMConstInt(LLVMInt32TypeInContext(module.context.0.lock_arc().context), expr.variant as _, LLVMBool::from(false))
+        }
         ResolvedExpr::LiteralBool(expr) => {
             LLVMConstInt(LLVMInt1TypeInContext(module.context.0.lock_arc().context), if expr { 1 } else { 0 }, LLVMBool::from(false))
         }
@@ -303,6 +625,31 @@ pub unsafe fn emitExpr(module: &mut CompiledModule, expr: ResolvedExpr) -> LLVMV
             ];
             LLVMConstStructInContext(module.context.0.lock_arc().context, properties.as_mut_ptr(), properties.len() as _, 0)
         }
+        ResolvedExpr::LiteralArray(expr) => {
+            let contextLock = module.context.0.lock_arc();
+            let arrayType = expr.ty.getLLVMType(contextLock.context);
+            let elementType = expr.ty.getArrayType().unwrap().elementType.getLLVMType(contextLock.context);
+            drop(contextLock);
+            let mut values = getOperands(module, expr.valueVec);
+            if values.iter().all(|value| LLVMIsConstant(*value) != 0) {
+                LLVMConstArray(elementType, values.as_mut_ptr(), values.len() as _)
+            } else {
+                // non-constant elements are inserted into the array one at a time
+                let name = CString::new("array_literal").unwrap();
+                values.into_iter().enumerate().fold(LLVMGetUndef(arrayType), |array, (index, value)| LLVMBuildInsertValue(module.builder, array, value, index as _, name.as_ptr()))
+            }
+        }
+        ResolvedExpr::LiteralTuple(expr) => {
+            let tupleType = expr.ty.getLLVMType(module.context.0.lock_arc().context);
+            let mut values = getOperands(module, expr.valueVec);
+            if values.iter().all(|value| LLVMIsConstant(*value) != 0) {
+                LLVMConstStructInContext(module.context.0.lock_arc().context, values.as_mut_ptr(), values.len() as _, 0)
+            } else {
+                // non-constant elements are inserted into the tuple one at a time
+                let name = CString::new("tuple_literal").unwrap();
+                values.into_iter().enumerate().fold(LLVMGetUndef(tupleType), |tuple, (index, value)| LLVMBuildInsertValue(module.builder, tuple, value, index as _, name.as_ptr()))
+            }
+        }
     };
 }
 
@@ -318,7 +665,9 @@ impl Next {
         let name = CString::new(name).unwrap();
         match self {
             Next::End => {
+                // continues from the end of the previous block
                 let block = LLVMAppendBasicBlockInContext(module.context.0.lock_arc().context, function, name.as_ptr());
+                emitBranch(module, block);
                 LLVMPositionBuilderAtEnd(module.builder, block);
             }
             // Next::Before(block) => {
@@ -336,6 +685,17 @@ impl Next {
     }
 }
 
+unsafe fn isTerminated(module: &CompiledModule) -> bool {
+    return !LLVMGetBasicBlockTerminator(LLVMGetInsertBlock(module.builder)).is_null();
+}
+
+// branch from the end of a block, unless the block has already been terminated (e.g. by a return)
+unsafe fn emitBranch(module: &mut CompiledModule, block: LLVMBasicBlockRef) {
+    if !isTerminated(module) {
+        LLVMBuildBr(module.builder, block);
+    }
+}
+
 unsafe fn emitScope(module: &mut CompiledModule, branch: bool, name: &str, function: LLVMValueRef, scope: ResolvedScope, basicBlockCallback: impl FnOnce(&mut CompiledModule, LLVMContextRef, &CString) -> LLVMBasicBlockRef, startCallback: impl FnOnce(&mut CompiledModule), endCallback: impl FnOnce(&mut CompiledModule) -> Next) -> LLVMBasicBlockRef {
     let contextLock = module.context.0.lock_arc();
     let context = contextLock.context;
@@ -349,6 +709,10 @@ unsafe fn emitScope(module: &mut CompiledModule, branch: bool, name: &str, funct
     LLVMPositionBuilderAtEnd(module.builder, basicBlock);
     startCallback(module);
     for statement in scope.statementVec {
+        if isTerminated(module) {
+            // remaining statements are unreachable (reported by the resolver)
+            break;
+        }
         emit(module, function, statement);
     }
     endCallback(module).setBlock(module, function, &format!("after_{name}"));
@@ -365,6 +729,28 @@ fn wrapInScope(statement: Statement) -> ResolvedScope {
     };
 }
 
+// the name of each variant is stored in a table (created once per enum in the module), indexed by the enum value
+unsafe fn emitEnumName(module: &mut CompiledModule, ty: &Type, value: LLVMValueRef) -> LLVMValueRef {
+    let tableName = CString::new(format!("enum_names_{}", ty.getTypeName())).unwrap();
+    let mut table = LLVMGetNamedGlobal(module.module, tableName.as_ptr());
+    if table.is_null() {
+        let variants = ty.getEnumType().unwrap().getVariants();
+        let mut names = variants.iter().map(|variant| emitExpr(module, ResolvedExpr::LiteralString(variant.to_owned()))).collect::<Vec<_>>();
+        // enums always have at least one variant
+        let stringType = LLVMTypeOf(names[0]);
+        table = LLVMAddGlobal(module.module, LLVMArrayType(stringType, names.len() as _), tableName.as_ptr());
+        LLVMSetInitializer(table, LLVMConstArray(stringType, names.as_mut_ptr(), names.len() as _));
+        LLVMSetGlobalConstant(table, 1);
+    }
+    let tableType = LLVMGlobalGetValueType(table);
+    let stringType = LLVMGetElementType(tableType);
+    let mut indices = [emitExpr(module, ResolvedExpr::LiteralInteger(0)), value];
+    let name = CString::new("enum_name_pointer").unwrap();
+    let pointer = LLVMBuildInBoundsGEP2(module.builder, tableType, table, indices.as_mut_ptr(), indices.len() as _, name.as_ptr());
+    let name = CString::new("enum_name").unwrap();
+    return LLVMBuildLoad2(module.builder, stringType, pointer, name.as_ptr());
+}
+
 unsafe fn getFunctionValue(module: &mut CompiledModule, function: Function) -> (LLVMValueRef, LLVMTypeRef) {
     match module.functionMap.entry(function.id) {
         Entry::Occupied(v) => {
@@ -410,17 +796,53 @@ pub unsafe fn emit(module: &mut CompiledModule, function: LLVMValueRef, statemen
             drop(contextLock);
 
             emitScope(module, false, "if", function, wrapInScope(statement.statement), |_, _, _| ifBlock, |_| {}, |module| {
-                LLVMBuildBr(module.builder, endBlock);
+                emitBranch(module, endBlock);
                 Next::Block(endBlock)
             });
             emitScope(module, false, "else", function, wrapInScope(statement.elseStatement.unwrap_or(Statement::Scope(ResolvedScope {
                 statementVec: Vec::new(),
             }))), |_, _, _| elseBlock, |_| {}, |module| {
-                LLVMBuildBr(module.builder, endBlock);
+                emitBranch(module, endBlock);
                 Next::Block(endBlock)
             });
             branch
         }
+        Statement::Match(statement) => {
+            let statement = *statement;
+            let value = emitExpr(module, statement.value);
+            let contextLock = module.context.0.lock_arc();
+            let context = contextLock.context;
+            let name = CString::new("match_end").unwrap();
+            let endBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+            // matches without an else are exhaustive, so the default block is only reached by an invalid variant value
+            let name = CString::new("match_else").unwrap();
+            let elseBlock = LLVMInsertBasicBlockInContext(context, endBlock, name.as_ptr());
+            let name = CString::new("match_case").unwrap();
+            let caseBlocks = statement.caseVec.iter().map(|_| LLVMInsertBasicBlockInContext(context, elseBlock, name.as_ptr())).collect::<Vec<_>>();
+            let variantType = LLVMInt32TypeInContext(context);
+            drop(contextLock);
+
+            let switch = LLVMBuildSwitch(module.builder, value, elseBlock, statement.caseVec.len() as _);
+            for (case, block) in statement.caseVec.into_iter().zip(caseBlocks) {
+                LLVMAddCase(switch, LLVMConstInt(variantType, case.variant as _, LLVMBool::from(false)), block);
+                emitScope(module, false, "case", function, wrapInScope(case.statement), |_, _, _| block, |_| {}, |module| {
+                    emitBranch(module, endBlock);
+                    Next::Block(endBlock)
+                });
+            }
+            if let Some(elseStatement) = statement.elseStatement {
+                emitScope(module, false, "else", function, wrapInScope(elseStatement), |_, _, _| elseBlock, |_| {}, |module| {
+                    emitBranch(module, endBlock);
+                    Next::Block(endBlock)
+                });
+            } else {
+                // trap rather than marking the block unreachable, so an invalid value is not undefined behavior
+                LLVMPositionBuilderAtEnd(module.builder, elseBlock);
+                emitTrap(module);
+                LLVMPositionBuilderAtEnd(module.builder, endBlock);
+            }
+            switch
+        }
         Statement::While(statement) => {
             let contextLock = module.context.0.lock_arc();
             let context = contextLock.context;
@@ -441,13 +863,85 @@ pub unsafe fn emit(module: &mut CompiledModule, function: LLVMValueRef, statemen
             let name = CString::new("while_condition").unwrap();
             let condition = LLVMBuildICmp(module.builder, LLVMIntPredicate::LLVMIntNE, condition, falseValue, name.as_ptr());
             LLVMBuildCondBr(module.builder, condition, whileBlock, endBlock);
+            module.loopMap.insert(statement.id, (cmpBlock, endBlock));
 
             emitScope(module, false, "", function, wrapInScope(statement.statement), |_, _, _| whileBlock, |_| {}, |module| {
-                LLVMBuildBr(module.builder, cmpBlock);
+                emitBranch(module, cmpBlock);
+                Next::Block(endBlock)
+            });
+            LLVMConstNull(LLVMInt8TypeInContext(module.context.0.lock_arc().context))
+        }
+        Statement::For(statement) => {
+            let variable = ResolvedVariable {
+                ty: statement.variable.ty.to_owned(),
+                id: statement.variable.id,
+            };
+            emitExpr(module, ResolvedExpr::VariableInitialize(Box::new(VariableInitialize {
+                variable: statement.variable,
+                value: statement.start,
+            })));
+            let end = emitExpr(module, statement.end);
+
+            let contextLock = module.context.0.lock_arc();
+            let context = contextLock.context;
+            let name = CString::new("for_cmp").unwrap();
+            let cmpBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+            let name = CString::new("for_end").unwrap();
+            let endBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+
+            let name = CString::new("for_block").unwrap();
+            let forBlock = LLVMInsertBasicBlockInContext(context, endBlock, name.as_ptr());
+            // continue jumps to the increment, rather than directly to the comparison
+            let name = CString::new("for_step").unwrap();
+            let stepBlock = LLVMInsertBasicBlockInContext(context, endBlock, name.as_ptr());
+
+            drop(contextLock);
+
+            LLVMBuildBr(module.builder, cmpBlock);
+            LLVMPositionBuilderAtEnd(module.builder, cmpBlock);
+            let value = emitExpr(module, ResolvedExpr::Variable(variable.to_owned()));
+            let condition = basicOperator(module, Operator::Less, INTEGER_TYPE.to_owned(), vec![value, end]);
+            LLVMBuildCondBr(module.builder, condition, forBlock, endBlock);
+            module.loopMap.insert(statement.id, (stepBlock, endBlock));
+
+            emitScope(module, false, "", function, wrapInScope(statement.statement), |_, _, _| forBlock, |_| {}, |module| {
+                emitBranch(module, stepBlock);
+                Next::Block(stepBlock)
+            });
+            emitExpr(module, ResolvedExpr::Operator(Box::new(ResolvedOperator {
+                operator: Operator::PlusAssign,
+                operands: Box::new([ResolvedExpr::Variable(variable), ResolvedExpr::LiteralInteger(1)]),
+                expressionType: INTEGER_TYPE.to_owned(),
+            })));
+            LLVMBuildBr(module.builder, cmpBlock);
+            LLVMPositionBuilderAtEnd(module.builder, endBlock);
+            LLVMConstNull(LLVMInt8TypeInContext(module.context.0.lock_arc().context))
+        }
+        Statement::Loop(statement) => {
+            let contextLock = module.context.0.lock_arc();
+            let context = contextLock.context;
+            let name = CString::new("loop_block").unwrap();
+            let loopBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+            let name = CString::new("loop_end").unwrap();
+            let endBlock = LLVMAppendBasicBlockInContext(context, function, name.as_ptr());
+
+            drop(contextLock);
+
+            module.loopMap.insert(statement.id, (loopBlock, endBlock));
+            emitScope(module, true, "", function, wrapInScope(statement.statement), |_, _, _| loopBlock, |_| {}, |module| {
+                emitBranch(module, loopBlock);
                 Next::Block(endBlock)
             });
             LLVMConstNull(LLVMInt8TypeInContext(module.context.0.lock_arc().context))
         }
+        Statement::Break(statement) => {
+            let (_, breakBlock) = module.loopMap[&statement.loopId];
+            LLVMBuildBr(module.builder, breakBlock)
+        }
+        Statement::Continue(statement) => {
+            let (continueBlock, _) = module.loopMap[&statement.loopId];
+            LLVMBuildBr(module.builder, continueBlock)
+        }
         Statement::Return(statement) => {
             if let Some(expr) = statement.expr {
                 LLVMBuildRet(module.builder, emitExpr(module, expr))
@@ -461,6 +955,12 @@ pub unsafe fn emit(module: &mut CompiledModule, function: LLVMValueRef, statemen
         Statement::Print(statement) => {
             let ty = statement.value.getExpressionType();
             let value = emitExpr(module, statement.value);
+            // enums are printed as the name of their variant
+            let (ty, value) = if ty.getEnumType().is_some() {
+                (STRING_TYPE.to_owned(), emitEnumName(module, &ty, value))
+            } else {
+                (ty, value)
+            };
             let valueName = CString::new("").unwrap();
 
             if ty == INTEGER_TYPE {
@@ -523,17 +1023,22 @@ pub unsafe fn emit(module: &mut CompiledModule, function: LLVMValueRef, statemen
                 block
             }, |module| {
                 for index in 0..parameters.len() {
-                    let parameterVariable = emitExpr(module, ResolvedExpr::VariableDeclaration(VariableDeclare {
+                    let parameterVariable = emitVariableAllocation(module, &VariableDeclare {
                         ty: parameters[index].ty.to_owned(),
                         id: statement.parameterVecId[index],
                         global: false,
-                    }));
+                    });
                     let parameterValue = LLVMGetParam(function, index as _);
                     LLVMBuildStore(module.builder, parameterValue, parameterVariable);
                 }
             }, |module| {
-                if functionReturnType == VOID_TYPE {
+                if isTerminated(module) {
+                    // function ends with a return
+                } else if functionReturnType == VOID_TYPE {
                     LLVMBuildRetVoid(module.builder);
+                } else {
+                    // end of a non-void function is unreachable, as every path returns (checked by resolver)
+                    LLVMBuildUnreachable(module.builder);
                 }
                 let prev = module.blockStack.pop().unwrap();
                 Next::Block(prev)
@@ -555,3 +1060,645 @@ pub unsafe fn emit(module: &mut CompiledModule, function: LLVMValueRef, statemen
         }
     };
 }
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroUsize;
+    use std::path::PathBuf;
+    use std::process::{Command, Output};
+
+    use crate::backend::backendoptions::BackendOptions;
+    use crate::backend::CompiledModule;
+    use crate::compiler::Compiler;
+    use crate::options::{EmitType, ErrorFormat};
+
+    fn getIR(name: &str, source: &str) -> String {
+        return getIRWithOptions(name, source, BackendOptions::default());
+    }
+
+    // compiles the source in a directory unique to the name (tests may run in parallel)
+    // returns the compiled module and the directory, which output files should be written to
+    fn compileSource(name: &str, source: &str, emitType: EmitType, options: BackendOptions) -> (CompiledModule, PathBuf) {
+        let directory = std::env::temp_dir().join("LLVM-Compiler-test").join(name);
+        std::fs::create_dir_all(&directory).unwrap();
+        let sourcePath = directory.join(format!("{name}.txt"));
+        std::fs::write(&sourcePath, source).unwrap();
+
+        let compiler = Compiler::new(NonZeroUsize::new(1), vec![sourcePath.to_string_lossy().into_owned()], emitType, ErrorFormat::Human, options);
+        let module = compiler.getCompiledResult().expect("compilation failed");
+        return (module, directory);
+    }
+
+    fn getIRWithOptions(name: &str, source: &str, options: BackendOptions) -> String {
+        let (module, directory) = compileSource(name, source, EmitType::LlvmIr, options);
+        let outputPath = directory.join(format!("{name}.ll"));
+        assert!(module.writeIR(&outputPath).is_ok(), "failed to write IR");
+        return std::fs::read_to_string(&outputPath).unwrap();
+    }
+
+    // builds and runs an executable, providing its output
+    // requires the sdk to have been built (see lib/sdk)
+    fn getOutput(name: &str, source: &str) -> String {
+        let output = runExecutable(name, source);
+        assert!(output.status.success(), "executable failed: {:?}", output.status);
+        return String::from_utf8(output.stdout).unwrap();
+    }
+
+    fn runExecutable(name: &str, source: &str) -> Output {
+        return runExecutableWithOptions(name, source, BackendOptions::default());
+    }
+
+    fn runExecutableWithOptions(name: &str, source: &str, options: BackendOptions) -> Output {
+        let (module, directory) = compileSource(name, source, EmitType::Exe, options);
+        let executablePath = directory.join(name);
+        assert!(module.writeExecutable(&executablePath).is_ok(), "failed to write executable");
+        return Command::new(&executablePath).output().unwrap();
+    }
+
+    // label of the basic block containing the first line which contains the provided text
+    fn getBlockContaining(ir: &str, text: &str) -> String {
+        let mut label = None;
+        for line in ir.lines() {
+            if !line.starts_with(' ') && line.contains(':') {
+                label = Some(line.split(':').next().unwrap().to_owned());
+            } else if line.contains(text) {
+                return label.expect("expected basic block");
+            }
+        }
+        panic!("missing {text:?} in IR\n{ir}");
+    }
+
+    const TOUCH_FUNCTION: &str = "\
+bool touch() {
+    print(1)
+    return true
+}
+";
+
+    #[test]
+    fn testBreakContinue() {
+        let output = getOutput("testBreakContinue", "\
+int i = 0
+outer: while i < 3 {
+    i += 1
+    int j = 0
+    while true {
+        j += 1
+        if j == 2 {
+            continue
+        }
+        if j > 3 {
+            continue outer
+        }
+        if i == 3 {
+            break outer
+        }
+        print(i * 10 + j)
+    }
+}
+print(i)
+");
+
+        // inner continue skips j == 2, labeled continue resumes the outer loop, labeled break exits both loops
+        assert_eq!("11\n13\n21\n23\n3\n", output);
+    }
+
+    #[test]
+    fn testForLoop() {
+        let output = getOutput("testForLoop", "\
+int n = 3
+for i in 0..n {
+    print(i)
+}
+outer: for i in 1..3 {
+    for j in 0..10 {
+        if j == 2 {
+            continue outer
+        }
+        print(i * 10 + j)
+    }
+}
+for i in 5..2 {
+    print(i)
+}
+");
+
+        // upper bound is exclusive, empty ranges do not run
+        assert_eq!("0\n1\n2\n10\n11\n20\n21\n", output);
+    }
+
+    #[test]
+    fn testForContinue() {
+        let ir = getIR("testForContinue", "\
+for i in 0..4 {
+    if i == 1 {
+        continue
+    }
+    print(i)
+}
+");
+
+        // continue increments the counter before the next comparison
+        assert_eq!("if_block", getBlockContaining(&ir, "br label %for_step"), "{ir}");
+        assert_eq!("for_step", getBlockContaining(&ir, "add i32"), "{ir}");
+        // comparison uses the exclusive upper bound
+        assert!(ir.contains("icmp slt i32"), "{ir}");
+    }
+
+    #[test]
+    fn testLoop() {
+        let output = getOutput("testLoop", "\
+int firstSquareAbove(int n) {
+    int i = 0
+    loop {
+        if i * i > n {
+            return i
+        }
+        i += 1
+    }
+}
+print(firstSquareAbove(10))
+int count = 0
+outer: loop {
+    loop {
+        count += 1
+        if count == 3 {
+            break outer
+        }
+        continue outer
+    }
+}
+print(count)
+");
+
+        // the return inside the loop is the only exit from the function
+        assert_eq!("4\n3\n", output);
+    }
+
+    #[test]
+    fn testLetInference() {
+        let output = getOutput("testLetInference", "\
+class Point {
+    let x = 3
+    let y = 1.5
+    let name = \"point\"
+}
+let a = 5
+let b = a * 2
+Point p
+print(b)
+print(p.x)
+print(p.y)
+print(p.name)
+float half(int value) {
+    let result = value as float / 2.0
+    return result
+}
+print(half(b))
+");
+
+        assert_eq!("10\n3\n1.5\npoint\n5\n", output);
+    }
+
+    #[test]
+    fn testDeclarationInitializer() {
+        let source = "\
+int counter = 5
+int next() {
+    counter += 1
+    return counter
+}
+int first = next()
+int second = next()
+int local() {
+    int x = 7
+    return x
+}
+print(first)
+print(second)
+print(local())
+";
+        let ir = getIR("testDeclarationInitializer", source);
+
+        // constant global initializers are set on the global
+        assert!(ir.contains("@Global_int = global i32 5"), "{ir}");
+        // locals are initialized with a single store
+        assert!(ir.contains("store i32 7, ptr %Allocate_int"), "{ir}");
+        assert!(!ir.contains("store i32 0, ptr %Allocate_int"), "{ir}");
+
+        // non-constant global initializers run in source order
+        assert_eq!("6\n7\n7\n", getOutput("testDeclarationInitializerOutput", source));
+    }
+
+    #[test]
+    fn testCast() {
+        let source = "\
+int a = 7
+float f = a as float / 2.0
+print(f)
+print(f as int)
+print(-3.9 as int)
+char c = 'A'
+print(c as int)
+char d = (c as int + 1) as char
+print(d as int)
+bool yes = true
+print(yes as int)
+print(false as int)
+print(300 as char as int)
+";
+        let ir = getIR("testCast", source);
+        assert!(ir.contains("sitofp i32"), "{ir}");
+        assert!(ir.contains("fptosi float"), "{ir}");
+        assert!(ir.contains("zext i8"), "{ir}");
+        assert!(ir.contains("zext i1"), "{ir}");
+        assert!(ir.contains("trunc i32"), "{ir}");
+
+        // float to int truncates towards zero, int to char wraps
+        assert_eq!("3.5\n3\n-3\n65\n66\n1\n0\n44\n", getOutput("testCastOutput", source));
+    }
+
+    #[test]
+    fn testAndShortCircuit() {
+        let ir = getIR("testAndShortCircuit", &format!("{TOUCH_FUNCTION}bool a = false\nprint((a and touch()) as int)\n"));
+
+        // touch() is only called if a is true
+        assert_eq!("and_rhs", getBlockContaining(&ir, "call i1 @touch()"), "{ir}");
+        assert!(ir.contains("label %and_rhs, label %and_end"), "{ir}");
+        // short circuit value comes from the main block (numbered per module)
+        assert!(ir.contains("phi i1 [ false, %start_main_"), "{ir}");
+        assert!(ir.contains("[ %call_touch, %and_rhs ]"), "{ir}");
+    }
+
+    #[test]
+    fn testOrShortCircuit() {
+        let ir = getIR("testOrShortCircuit", &format!("{TOUCH_FUNCTION}bool a = true\nprint((a or touch()) as int)\n"));
+
+        // touch() is only called if a is false
+        assert_eq!("or_rhs", getBlockContaining(&ir, "call i1 @touch()"), "{ir}");
+        assert!(ir.contains("label %or_end, label %or_rhs"), "{ir}");
+        assert!(ir.contains("phi i1 [ true, %start_main_"), "{ir}");
+        assert!(ir.contains("[ %call_touch, %or_rhs ]"), "{ir}");
+    }
+
+    #[test]
+    fn testNestedShortCircuit() {
+        let ir = getIR("testNestedShortCircuit", &format!("{TOUCH_FUNCTION}bool a = true\nbool b = false\nprint((a and (b or touch())) as int)\n"));
+
+        // touch() is only called if a is true and b is false
+        assert_eq!("or_rhs", getBlockContaining(&ir, "call i1 @touch()"), "{ir}");
+        assert_eq!("and_rhs", getBlockContaining(&ir, "label %or_end, label %or_rhs"), "{ir}");
+        // result of the or expression is the incoming value from the end of the and rhs
+        assert!(ir.contains("[ %operator_Or, %or_end ]"), "{ir}");
+    }
+
+    #[test]
+    fn testFloatComparison() {
+        let ir = getIR("testFloatComparison", "float a = 1.5\nprint((a < 2.5) as int)\nprint((a == 2.5) as int)\n");
+
+        assert!(ir.contains("fcmp olt float"), "{ir}");
+        assert!(ir.contains("fcmp oeq float"), "{ir}");
+        assert!(!ir.contains("icmp"), "{ir}");
+    }
+
+    #[test]
+    fn testFloatComparisonNaN() {
+        let output = getOutput("testFloatComparisonNaN", "\
+float zero = 0.0
+float nan = zero / zero
+float a = 1.5
+print((nan == nan) as int)
+print((nan != nan) as int)
+print((a != nan) as int)
+print((a < nan) as int)
+print((a != 1.5) as int)
+");
+
+        assert_eq!("0\n1\n1\n0\n0\n", output);
+    }
+
+    #[test]
+    fn testIntegerDivision() {
+        let ir = getIR("testIntegerDivision", "int a = 7\nprint(a / 2)\nprint(a % 2)\nfloat b = 7.5\nprint(b % 2.0)\n");
+
+        assert!(ir.contains("sdiv i32"), "{ir}");
+        assert!(!ir.contains("sdiv exact"), "{ir}");
+        assert!(ir.contains("srem i32"), "{ir}");
+        assert!(ir.contains("frem float"), "{ir}");
+        // division checks are disabled by default
+        assert!(!ir.contains("llvm.trap"), "{ir}");
+    }
+
+    #[test]
+    fn testDivisionTrap() {
+        let options = BackendOptions {
+            divisionByZeroTrap: true,
+            ..BackendOptions::default()
+        };
+        let output = runExecutableWithOptions("testDivisionTrap", "\
+int minimum = -2147483647 - 1
+int divisor = -1
+print(7 / divisor)
+print(7 % divisor)
+print(minimum / divisor)
+", options.to_owned());
+        assert!(!output.status.success(), "{:?}", output.status);
+        assert_eq!("-7\n0\n", String::from_utf8(output.stdout).unwrap());
+
+        let output = runExecutableWithOptions("testRemainderTrap", "\
+int minimum = -2147483647 - 1
+int divisor = -1
+print(minimum % divisor)
+", options.to_owned());
+        assert!(!output.status.success(), "{:?}", output.status);
+        assert_eq!("", String::from_utf8(output.stdout).unwrap());
+
+        let output = runExecutableWithOptions("testDivisionByZeroTrap", "int divisor = 0\nprint(1 / divisor)\n", options.to_owned());
+        assert!(!output.status.success(), "{:?}", output.status);
+
+        // the overflow may also fault in hardware, so check that it reaches the trap
+        let ir = getIRWithOptions("testDivisionTrapIR", "int a = 7\nint b = -1\nprint(a % b)\n", options);
+        assert!(ir.contains("icmp eq i32 %Load_int, -2147483648"), "{ir}");
+        assert_eq!("division_invalid", getBlockContaining(&ir, "call void @llvm.trap"), "{ir}");
+    }
+
+    #[test]
+    fn testIncrementDecrement() {
+        let output = getOutput("testIncrementDecrement", "\
+int a = 5
+print(a++)
+print(a)
+print(++a)
+print(a--)
+print(--a)
+a++
+a++
+--a
+print(a)
+");
+
+        assert_eq!("5\n6\n7\n7\n5\n6\n", output);
+    }
+
+    #[test]
+    fn testIncrementFloat() {
+        let output = getOutput("testIncrementFloat", "\
+float f = 1.5
+f++
+print(f)
+print(--f)
+");
+
+        assert_eq!("2.5\n1.5\n", output);
+    }
+
+    #[test]
+    fn testIncrementField() {
+        let output = getOutput("testIncrementField", "\
+class Counter {
+    int count
+}
+Counter counter
+counter.count++
+++counter.count
+print(counter.count--)
+print(counter.count)
+");
+
+        assert_eq!("2\n1\n", output);
+    }
+
+    #[test]
+    fn testAllPathsReturn() {
+        let output = getOutput("testAllPathsReturn", "\
+int sign(int x) {
+    if x < 0 {
+        return -1
+    } else if x == 0 {
+        return 0
+    } else {
+        return 1
+    }
+}
+print(sign(-5))
+print(sign(0))
+print(sign(5))
+");
+
+        assert_eq!("-1\n0\n1\n", output);
+    }
+
+    #[test]
+    fn testUnreachableNotEmitted() {
+        let ir = getIR("testUnreachableNotEmitted", "\
+int value() {
+    return 1
+    print(2)
+}
+print(value())
+");
+
+        // print following the return is not emitted
+        assert!(!ir.contains("call void @sdk_print_int(i32 2)"), "{ir}");
+        assert!(!ir.contains("after_return"), "{ir}");
+    }
+
+    #[test]
+    fn testArrayIndex() {
+        let output = getOutput("testArrayIndex", "\
+int[4] values = [1, 2, 3, 4]
+values[1] = 20
+values[2] += 10
+values[3]++
+int index = 0
+int total = 0
+while index < values.length {
+    total += values[index]
+    index++
+}
+print(total)
+print([7, 8, 9][1])
+");
+
+        assert_eq!("39\n8\n", output);
+    }
+
+    #[test]
+    fn testNestedArray() {
+        let output = getOutput("testNestedArray", "\
+class Grid {
+    int[2][3] cells
+}
+int sum(int[3] row) {
+    return row[0] + row[1] + row[2]
+}
+Grid grid
+grid.cells[1][2] = 7
+grid.cells[1][0] = 5
+print(sum(grid.cells[1]))
+print(grid.cells.length)
+print(grid.cells[0].length)
+");
+
+        assert_eq!("12\n2\n3\n", output);
+    }
+
+    #[test]
+    fn testArrayLengthSideEffects() {
+        let source = "\
+int calls = 0
+int[3] makeArray() {
+    calls += 1
+    return [1, 2, 3]
+}
+int[2] values = [4, 5]
+print(makeArray().length)
+print(values.length)
+print(calls)
+";
+        let ir = getIR("testArrayLengthSideEffects", source);
+        assert!(ir.contains("call [3 x i32] @makeArray()"), "{ir}");
+        // length of a variable does not load the variable
+        assert!(ir.contains("call void @sdk_print_int(i32 2)"), "{ir}");
+
+        assert_eq!("3\n2\n1\n", getOutput("testArrayLengthSideEffectsOutput", source));
+    }
+
+    #[test]
+    fn testTuple() {
+        let output = getOutput("testTuple", "\
+(int, int) divmod(int a, int b) {
+    return (a / b, a % b)
+}
+let (quotient, remainder) = divmod(17, 5)
+print(quotient)
+print(remainder)
+(int, (float, int)) nested = (1, (2.5, 3))
+nested.1.1 = quotient + 4
+print(nested.1.0)
+print(nested.0 + nested.1.1)
+");
+
+        assert_eq!("3\n2\n2.5\n8\n", output);
+    }
+
+    #[test]
+    fn testNestedTuple() {
+        let output = getOutput("testNestedTuple", "\
+(int, (char, int)) make() {
+    return (7, ('a', 9))
+}
+(int, (int, (int, float))) deep = (1, (2, (3, 4.5)))
+deep.1.1.0 = deep.1.1.0 * 10
+print(deep.1.1.1)
+print(deep.1.1.0 + deep.1.0)
+print(make().1.1)
+print(make().1.0 as int)
+");
+
+        assert_eq!("4.5\n32\n9\n97\n", output);
+    }
+
+    #[test]
+    fn testEnumMatch() {
+        let output = getOutput("testEnumMatch", "\
+enum Color { Red, Green, Blue }
+int score(Color color) {
+    match color {
+        Red: return 1
+        Green: return 2
+        Blue: return 3
+    }
+}
+Color color = Color.Blue
+print(color)
+print(score(color))
+if color == Color.Blue {
+    color = Color.Red
+}
+match color {
+    Red: print(\"red\")
+    else: print(\"other\")
+}
+");
+
+        assert_eq!("Blue\n3\nred\n", output);
+    }
+
+    #[test]
+    fn testMatchDefaultTrap() {
+        let ir = getIR("testMatchDefaultTrap", "\
+enum Color { Red, Green }
+Color color = Color.Green
+match color {
+    Red: print(1)
+    Green: print(2)
+}
+");
+
+        // an out of range variant value traps rather than being undefined behavior
+        assert!(getBlockContaining(&ir, "call void @llvm.trap").starts_with("match_else"), "{ir}");
+    }
+
+    #[test]
+    fn testIndexOutOfBounds() {
+        let output = runExecutable("testIndexOutOfBounds", "\
+int[3] values = [1, 2, 3]
+int index = 2
+print(values[index])
+index++
+print(values[index])
+");
+
+        assert_eq!(Some(101), output.status.code());
+        assert_eq!("3\n", String::from_utf8(output.stdout).unwrap());
+        let error = String::from_utf8(output.stderr).unwrap();
+        assert!(error.contains("testIndexOutOfBounds.txt:5: index 3 out of bounds for length 3"), "{error}");
+    }
+
+    #[test]
+    fn testStringIndexOutOfBounds() {
+        let output = runExecutable("testStringIndexOutOfBounds", "\
+String text = \"abc\"
+int index = -1
+print(text[index] as int)
+");
+
+        assert_eq!(Some(101), output.status.code());
+        let error = String::from_utf8(output.stderr).unwrap();
+        assert!(error.contains("testStringIndexOutOfBounds.txt:3: index -1 out of bounds for length 3"), "{error}");
+    }
+
+    #[test]
+    fn testBoundsCheckFileName() {
+        let ir = getIR("testBoundsCheckFileName", "\
+int[3] values = [1, 2, 3]
+String text = \"abc\"
+int get(int index) {
+    return values[index]
+}
+print(values[0] + values[1])
+print(text[2] as int)
+print(get(2))
+");
+
+        // each index is checked, sharing a single global for the file name
+        assert_eq!(4, ir.matches("call void @sdk_panic_bounds").count(), "{ir}");
+        assert_eq!(1, ir.lines().filter(|line| line.starts_with("@file_name")).count(), "{ir}");
+    }
+
+    #[test]
+    fn testNoBoundsChecks() {
+        let source = "\
+int[3] values = [1, 2, 3]
+print(values[1])
+";
+        assert!(getIR("testBoundsChecks", source).contains("sdk_panic_bounds"));
+
+        let options = BackendOptions {
+            boundsChecks: false,
+            ..BackendOptions::default()
+        };
+        assert!(!getIRWithOptions("testNoBoundsChecks", source, options).contains("sdk_panic_bounds"));
+    }
+}
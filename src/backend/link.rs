@@ -1,50 +1,116 @@
 use std::ffi::OsStr;
 use std::path::Path;
-use std::process::{Command, exit};
+use std::process::Command;
 
-use log::error;
+use crate::backend::backendoptions::BackendOptions;
+use crate::compiler::CompilerError;
 
-#[cfg(target_os = "windows")]
-const LLC_PATH: &str = "llc.exe";
-#[cfg(target_os = "windows")]
-const LD_PATH: &str = "lld-link.exe";
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LinkerFlavor {
+    Elf,
+    Coff,
+    MachO,
+}
 
-#[cfg(target_os = "linux")]
-const LLC_PATH: &str = "llc";
-#[cfg(target_os = "linux")]
-const LD_PATH: &str = "ld.lld";
+impl LinkerFlavor {
+    pub fn new(triple: &str) -> Self {
+        return if triple.contains("windows") {
+            LinkerFlavor::Coff
+        } else if triple.contains("apple") || triple.contains("darwin") || triple.contains("macos") {
+            LinkerFlavor::MachO
+        } else {
+            LinkerFlavor::Elf
+        };
+    }
 
-#[cfg(target_os = "windows")]
-pub fn getExecutableExtension() -> &'static str {
-    return ".exe";
+    pub fn getExecutableExtension(&self) -> &'static str {
+        return match self {
+            LinkerFlavor::Coff => ".exe",
+            LinkerFlavor::Elf | LinkerFlavor::MachO => "",
+        };
+    }
+
+    pub fn getObjectExtension(&self) -> &'static str {
+        return match self {
+            LinkerFlavor::Coff => ".obj",
+            LinkerFlavor::Elf | LinkerFlavor::MachO => ".o",
+        };
+    }
+
+    // whether the C runtime is responsible for program startup (and calls main)
+    pub fn requiresMainFunction(&self) -> bool {
+        return match self {
+            LinkerFlavor::Coff => false,
+            LinkerFlavor::Elf | LinkerFlavor::MachO => true,
+        };
+    }
+
+    fn getLinkerPath(&self, options: &BackendOptions) -> String {
+        if let Some(linker) = &options.linker {
+            return linker.to_owned();
+        }
+        return match self {
+            // ld.lld is not invoked directly, as linking against libc requires the C runtime startup files and library paths of the target,
+            // which the compiler driver provides (the driver links with lld when --target is given, see linkElf)
+            // the host compiler driver may use its default linker rather than lld
+            LinkerFlavor::Elf => if options.targetTriple.is_some() {
+                "clang"
+            } else {
+                "cc"
+            },
+            LinkerFlavor::Coff => "lld-link",
+            LinkerFlavor::MachO => "ld64.lld",
+        }.to_owned();
+    }
+
+    fn getCheckArg(&self) -> &'static str {
+        return match self {
+            LinkerFlavor::Elf | LinkerFlavor::MachO => "--version",
+            LinkerFlavor::Coff => "-help",
+        };
+    }
+}
+
+fn checkPath(path: &str, arg: impl AsRef<OsStr>) -> Result<(), CompilerError> {
+    let output = Command::new(path).arg(arg).output().map_err(|_| CompilerError::LinkError(format!("unable to find {path}, is it available on path?")))?;
+    return if output.status.success() {
+        Ok(())
+    } else {
+        Err(CompilerError::LinkError(format!("error when running {path}, program returned exit code {:?}", output.status)))
+    };
 }
 
-#[cfg(target_os = "linux")]
-pub fn getExecutableExtension() -> &'static str {
-    return "";
+pub fn checkLinkerPath(options: &BackendOptions) -> Result<(), CompilerError> {
+    let flavor = LinkerFlavor::new(&options.getTargetTriple());
+    return checkPath(&flavor.getLinkerPath(options), flavor.getCheckArg());
 }
 
-fn checkPath(path: &str, arg: impl AsRef<OsStr>) {
-    if let Ok(output) = Command::new(path).arg(arg).output() {
-        if !output.status.success() {
-            error!("error when running {path}, program returned exit code {:?}", output.status);
-            exit(3);
-        }
+fn getSdkPath(flavor: LinkerFlavor, options: &BackendOptions) -> String {
+    let libraryName = match flavor {
+        LinkerFlavor::Coff => "sdk.lib",
+        LinkerFlavor::Elf | LinkerFlavor::MachO => "libsdk.a",
+    };
+    // sdk is expected to be built using "cargo build --target <triple>" when cross compiling
+    return if let Some(triple) = &options.targetTriple {
+        format!("lib/sdk/target/{triple}/debug/{libraryName}")
     } else {
-        error!("unable to find {path}, is it available on path?");
-        exit(2);
-    }
+        format!("lib/sdk/target/debug/{libraryName}")
+    };
 }
 
-pub fn checkLinkerPath() {
-    checkPath(LD_PATH, "-help");
-    checkPath(LLC_PATH, "--help");
+fn runCommand(path: &str, command: &mut Command) -> Result<(), CompilerError> {
+    let output = command.output().map_err(|error| CompilerError::LinkError(format!("failed to run {path}: {error}")))?;
+    return if output.status.success() {
+        Ok(())
+    } else {
+        Err(CompilerError::LinkError(format!("{path} returned exit code {:?}\n{}", output.status, String::from_utf8_lossy(&output.stderr).trim_end())))
+    };
 }
 
-#[cfg(windows)]
-fn link(entryName: &str, bitcodePath: impl AsRef<Path>, executablePath: impl AsRef<Path>) -> bool {
-    return Command::new(LD_PATH)
-        .arg(format!("/out:{}", executablePath.as_ref().as_os_str().to_str().unwrap()))
+fn linkCoff(entryName: &str, options: &BackendOptions, objectPath: &Path, executablePath: &Path) -> Result<(), CompilerError> {
+    let linkerPath = LinkerFlavor::Coff.getLinkerPath(options);
+    return runCommand(&linkerPath, Command::new(&linkerPath)
+        .arg(format!("/out:{}", executablePath.as_os_str().to_str().unwrap()))
         .arg(format!("/entry:{entryName}"))
         // statically linking to sdk requires the following libs
         .arg(format!("/defaultlib:libucrt.lib"))
@@ -54,30 +120,106 @@ fn link(entryName: &str, bitcodePath: impl AsRef<Path>, executablePath: impl AsR
         .arg(format!("/defaultlib:bcrypt.lib"))
         .arg(format!("/defaultlib:userenv.lib"))
         .arg(format!("/defaultlib:advapi32.lib"))
-        .arg(format!("/defaultlib:lib/sdk/target/debug/sdk.lib"))
+        .arg(format!("/defaultlib:{}", getSdkPath(LinkerFlavor::Coff, options)))
         .arg(format!("/safeseh:no"))
         .arg("/subsystem:console")
-        .arg(bitcodePath.as_ref().as_os_str())
-        .status().expect(&format!("failed to run {LD_PATH}")).success();
+        .arg(objectPath.as_os_str()));
+}
+
+// entry is called from the generated main function (see CompiledModule::writeExecutable)
+fn linkElf(options: &BackendOptions, objectPath: &Path, executablePath: &Path) -> Result<(), CompilerError> {
+    let linkerPath = LinkerFlavor::Elf.getLinkerPath(options);
+    let mut command = Command::new(&linkerPath);
+    if let Some(triple) = &options.targetTriple {
+        command.arg(format!("--target={triple}")).arg("-fuse-ld=lld");
+    }
+    return runCommand(&linkerPath, command
+        .arg("-o").arg(executablePath.as_os_str())
+        .arg(objectPath.as_os_str())
+        .arg(getSdkPath(LinkerFlavor::Elf, options))
+        // statically linking to sdk requires the following libs
+        .arg("-lpthread")
+        .arg("-ldl")
+        .arg("-lm"));
+}
+
+// used if the triple does not specify an os version (e.g. x86_64-apple-darwin)
+// 11.0 is the first version supporting arm64
+const DEFAULT_MACOS_VERSION: &str = "11.0";
+
+// minimum os version from the os component of the triple (e.g. "12.3" for arm64-apple-macosx12.3.0)
+fn getMacOSVersion(triple: &str) -> &str {
+    let os = triple.split('-').nth(2).unwrap_or_default();
+    let version = os.strip_prefix("macosx").or_else(|| os.strip_prefix("macos")).unwrap_or_default();
+    return if version.is_empty() {
+        DEFAULT_MACOS_VERSION
+    } else {
+        version
+    };
 }
 
-#[cfg(linux)]
-fn link(bitcodePath: impl AsRef<Path>, executablePath: impl AsRef<Path>) -> bool {
-    return Command::new(LD_PATH)
-        .arg("-o").arg(executablePath.as_ref())
-        .arg(format!("entry={entryName}"))
-        .arg(bitcodePath.as_ref().as_os_str())
-        .status().expect(&format!("failed to run {LD_PATH}")).success();
+// entry is called from the generated main function (see CompiledModule::writeExecutable)
+fn linkMachO(options: &BackendOptions, objectPath: &Path, executablePath: &Path) -> Result<(), CompilerError> {
+    let linkerPath = LinkerFlavor::MachO.getLinkerPath(options);
+    let triple = options.getTargetTriple();
+    let arch = match triple.split('-').next().unwrap_or_default() {
+        "aarch64" => "arm64",
+        arch => arch,
+    };
+    return runCommand(&linkerPath, Command::new(&linkerPath)
+        .arg("-arch").arg(arch)
+        // minimum version, sdk version
+        .arg("-platform_version").arg("macos").arg(getMacOSVersion(&triple)).arg(getMacOSVersion(&triple))
+        .arg("-o").arg(executablePath.as_os_str())
+        .arg(objectPath.as_os_str())
+        .arg(getSdkPath(LinkerFlavor::MachO, options))
+        .arg("-lSystem"));
 }
 
-pub(in super) fn linkExecutable(entryName: &str, bitcodePath: impl AsRef<Path>, executablePath: impl AsRef<Path>) {
-    Command::new(LLC_PATH)
-        .arg("-o").arg(executablePath.as_ref().as_os_str())
-        .arg(bitcodePath.as_ref().as_os_str())
-        .spawn().expect(&format!("failed to run {LLC_PATH}"));
+pub(in super) fn linkExecutable(entryName: &str, options: &BackendOptions, objectPath: impl AsRef<Path>, executablePath: impl AsRef<Path>) -> Result<(), CompilerError> {
     assert!(!entryName.is_empty());
-    if !(link(entryName, bitcodePath, executablePath)) {
-        error!("Linking failed");
-        exit(5);
+    return match LinkerFlavor::new(&options.getTargetTriple()) {
+        LinkerFlavor::Elf => linkElf(options, objectPath.as_ref(), executablePath.as_ref()),
+        LinkerFlavor::Coff => linkCoff(entryName, options, objectPath.as_ref(), executablePath.as_ref()),
+        LinkerFlavor::MachO => linkMachO(options, objectPath.as_ref(), executablePath.as_ref()),
+    };
+}
+
+#[cfg(test)]
+mod test {
+    use crate::backend::backendoptions::BackendOptions;
+    use crate::backend::link::{getMacOSVersion, LinkerFlavor};
+
+    #[test]
+    fn testLinkerFlavor() {
+        assert_eq!(LinkerFlavor::Elf, LinkerFlavor::new("aarch64-unknown-linux-gnu"));
+        assert_eq!(LinkerFlavor::Coff, LinkerFlavor::new("x86_64-pc-windows-msvc"));
+        assert_eq!(LinkerFlavor::MachO, LinkerFlavor::new("arm64-apple-macosx12.0.0"));
+    }
+
+    #[test]
+    fn testLinkerPath() {
+        let options = BackendOptions::default();
+        assert_eq!("cc", LinkerFlavor::Elf.getLinkerPath(&options));
+        assert_eq!("lld-link", LinkerFlavor::Coff.getLinkerPath(&options));
+        let options = BackendOptions {
+            targetTriple: Some("aarch64-unknown-linux-gnu".to_owned()),
+            ..BackendOptions::default()
+        };
+        assert_eq!("clang", LinkerFlavor::Elf.getLinkerPath(&options));
+        let options = BackendOptions {
+            linker: Some("gcc-12".to_owned()),
+            ..BackendOptions::default()
+        };
+        assert_eq!("gcc-12", LinkerFlavor::Elf.getLinkerPath(&options));
+        assert_eq!("gcc-12", LinkerFlavor::MachO.getLinkerPath(&options));
+    }
+
+    #[test]
+    fn testMacOSVersion() {
+        assert_eq!("12.3.0", getMacOSVersion("arm64-apple-macosx12.3.0"));
+        assert_eq!("13.0", getMacOSVersion("x86_64-apple-macos13.0"));
+        assert_eq!("11.0", getMacOSVersion("x86_64-apple-darwin"));
+        assert_eq!("11.0", getMacOSVersion("x86_64-apple-macosx"));
     }
 }
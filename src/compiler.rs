@@ -7,42 +7,85 @@ use std::sync::Arc;
 use std::thread::{Builder, JoinHandle};
 
 use anyhow::Error;
-use log::error;
+use log::{error, warn};
 use parking_lot::Mutex;
+use strum_macros::IntoStaticStr;
 
 use crate::ast::{AbstractSyntaxTree, ASTError};
 use crate::backend::{CompiledModule, Context};
-use crate::module::{Module, ParseError, SourceFile};
+use crate::backend::backendoptions::BackendOptions;
+use crate::diagnostic::Diagnostic;
+use crate::module::{Module, ParseError, SourceFile, TokenType};
+use crate::options::{EmitType, ErrorFormat};
 use crate::resolver::exporttable::GlobalExportTable;
 use crate::resolver::resolutionerror::ResolutionError;
 use crate::resolver::Resolver;
 
+#[derive(IntoStaticStr)]
 pub enum CompilerError {
     ReadSourceError(Error),
     TokenParseError(ParseError),
-    ASTParseError(ASTError),
+    ASTParseError(Vec<ASTError>),
     ResolutionError(Vec<ResolutionError>),
+    EmitError(String),
+    LinkError(String),
 }
 
 impl CompilerError {
-    pub fn getErrorMessage(&self) -> String {
+    pub fn getDiagnostics(&self) -> Vec<Diagnostic> {
         return match self {
-            CompilerError::ReadSourceError(error) => format!("Failed to read source: {error}"),
-            CompilerError::TokenParseError(error) => format!("Failed to parse tokens: {}", error.getDisplayMessage()),
-            CompilerError::ASTParseError(error) => format!("Failed to match syntax: {}", error.getDisplayMessage()),
-            CompilerError::ResolutionError(error) => format!("Failed to resolve symbols: {error:?}"),
+            CompilerError::ReadSourceError(error) => vec![Diagnostic::error(format!("failed to read source: {error}"), None).withCode(self.into())],
+            CompilerError::TokenParseError(error) => vec![error.getDiagnostic()],
+            CompilerError::ASTParseError(errorVec) => errorVec.iter().map(|error| error.getDiagnostic()).collect(),
+            CompilerError::ResolutionError(errorVec) => errorVec.iter().map(|error| error.getDiagnostic()).collect(),
+            CompilerError::EmitError(error) => vec![Diagnostic::error(format!("failed to write output: {error}"), None).withCode(self.into())],
+            CompilerError::LinkError(error) => vec![Diagnostic::error(format!("failed to link: {error}"), None).withCode(self.into())],
         };
     }
+
+    pub fn getErrorMessage(&self) -> String {
+        return self.getDiagnostics().iter().map(|diagnostic| diagnostic.render()).collect::<Vec<_>>().join("\n");
+    }
+
+    pub fn report(&self, errorFormat: ErrorFormat) {
+        match errorFormat {
+            ErrorFormat::Human => error!("{}", self.getErrorMessage()),
+            // one diagnostic per line, written to stderr so that it is not mixed with stage output
+            ErrorFormat::Json => for diagnostic in self.getDiagnostics() {
+                eprintln!("{}", diagnostic.toJson());
+            },
+        }
+    }
+}
+
+fn reportWarnings(diagnostics: impl Iterator<Item = Diagnostic>, errorFormat: ErrorFormat) {
+    for diagnostic in diagnostics {
+        match errorFormat {
+            ErrorFormat::Human => warn!("{}", diagnostic.render()),
+            ErrorFormat::Json => eprintln!("{}", diagnostic.toJson()),
+        }
+    }
 }
 
+// result of a compilation stage
+// stages stop early (providing their output) if the requested emit type has been reached
+enum StageResult<T> {
+    Continue(T),
+    Output(String),
+}
+
+type JobResult = (CompiledModule, Vec<(usize, String)>);
+
 pub struct Compiler {
     context: Context,
+    options: BackendOptions,
+    errorFormat: ErrorFormat,
     exportTable: GlobalExportTable,
-    threads: Vec<JoinHandle<Option<CompiledModule>>>,
+    threads: Vec<JoinHandle<Option<JobResult>>>,
 }
 
 impl Compiler {
-    pub fn new(threadCount: Option<NonZeroUsize>, sourceVec: Vec<String>) -> Self {
+    pub fn new(threadCount: Option<NonZeroUsize>, sourceVec: Vec<String>, emitType: EmitType, errorFormat: ErrorFormat, options: BackendOptions) -> Self {
         let exportTable = GlobalExportTable::new();
         let threadCount = threadCount.unwrap_or(std::thread::available_parallelism().unwrap_or(NonZeroUsize::new(4).unwrap()));
         let threadCount = min(threadCount.into(), sourceVec.len());
@@ -52,64 +95,123 @@ impl Compiler {
         let context = Context::new();
 
         for _ in 0..threadCount {
-            handleVec.push(CompileJob::new(context.to_owned(), jobManager.to_owned()));
+            handleVec.push(CompileJob::new(context.to_owned(), options.to_owned(), jobManager.to_owned(), emitType, errorFormat));
         }
 
         return Self {
             context,
+            options,
+            errorFormat,
             exportTable,
             threads: handleVec,
         };
     }
 
-    fn compileFirstStage(exportTable: GlobalExportTable, source: String) -> Result<Resolver, CompilerError> {
+    fn getTokenOutput(output: &mut String, module: &Module, depth: usize) {
+        for token in module.getTokenVector() {
+            let indent = "    ".repeat(depth);
+            match token.getTokenType() {
+                TokenType::Parenthesis(parenthesisType, module) => {
+                    output.push_str(&format!("{indent}Parenthesis({parenthesisType:?})\n"));
+                    Self::getTokenOutput(output, module, depth + 1);
+                }
+                TokenType::CommaList(moduleVec) => {
+                    output.push_str(&format!("{indent}CommaList\n"));
+                    for module in moduleVec {
+                        Self::getTokenOutput(output, module, depth + 1);
+                    }
+                }
+                tokenType => output.push_str(&format!("{indent}{tokenType:?} {:?}\n", token.getSourceRange().getSourceInRange())),
+            }
+        }
+    }
+
+    fn compileFirstStage(exportTable: GlobalExportTable, source: String, emitType: EmitType) -> Result<StageResult<Resolver>, CompilerError> {
         // open source file
         let sourceFile = SourceFile::new(PathBuf::from(source)).map_err(|error| CompilerError::ReadSourceError(error))?;
         // break source file down into tokens
         let module = Module::new(sourceFile).map_err(|error| CompilerError::TokenParseError(error))?;
-        // info!("Module: {module:?}");
+        if let EmitType::Tokens = emitType {
+            let mut output = String::new();
+            Self::getTokenOutput(&mut output, &module, 0);
+            return Ok(StageResult::Output(output));
+        }
         // convert tokens into syntax expressions
-        let ast = AbstractSyntaxTree::new(module).map_err(|error| CompilerError::ASTParseError(error))?;
-        // info!("AST: {ast:?}");
+        let (ast, errorVec) = AbstractSyntaxTree::new(module);
+        if !errorVec.is_empty() {
+            return Err(CompilerError::ASTParseError(errorVec));
+        }
+        if let EmitType::Ast = emitType {
+            return Ok(StageResult::Output(format!("{ast:#?}")));
+        }
         // first step of resolution (identifying export symbols)
         // local exports will be resolved after all global symbols have been resolved
         // global exports will be resolved after all global symbols have been identified
         return match Resolver::new(ast, exportTable) {
-            Ok(resolver) => Ok(resolver),
+            Ok(resolver) => Ok(StageResult::Continue(resolver)),
             Err(err) => Err(CompilerError::ResolutionError(err))
         };
     }
 
-    fn compileSecondStage(context: Context, resolver: Resolver) -> Result<CompiledModule, CompilerError> {
+    fn compileSecondStage(context: Context, options: BackendOptions, resolver: Resolver, emitType: EmitType, errorFormat: ErrorFormat) -> Result<StageResult<CompiledModule>, CompilerError> {
         // second step of resolution (resolving all symbols using export tables (global and local))
-        let resolved = resolver.getResolvedAST().map_err(|error| CompilerError::ResolutionError(error))?;
-        // info!("Resolved: {resolved:?}");
+        let (resolved, warningVec) = resolver.getResolvedAST();
+        reportWarnings(warningVec.iter().map(|warning| warning.getDiagnostic()), errorFormat);
+        let resolved = resolved.map_err(|error| CompilerError::ResolutionError(error))?;
+        if let EmitType::Resolved = emitType {
+            return Ok(StageResult::Output(format!("{resolved:#?}")));
+        }
         // convert resolved ast into binary
         // source should be completely valid at this point; all errors should have been resolved
-        return Ok(CompiledModule::new(context, resolved));
+        return Ok(StageResult::Continue(CompiledModule::new(context, options, resolved)));
     }
 
     pub fn getCompiledResult(self) -> Option<CompiledModule> {
+        return self.getJobResult().map(|(compiledModule, _)| compiledModule);
+    }
+
+    // output of the last stage that was run, for emit types which end before the backend
+    pub fn getStageOutput(self) -> Option<String> {
+        let (_, mut outputVec) = self.getJobResult()?;
+        // sort output by source order
+        outputVec.sort_by_key(|(index, _)| *index);
+        return Some(outputVec.into_iter().map(|(_, output)| output).collect::<Vec<_>>().join("\n"));
+    }
+
+    fn getJobResult(self) -> Option<JobResult> {
         match self.exportTable.getExportErrorsBlocking() {
             Ok(_) => {
                 // do nothing
             }
             Err(err) => {
-                error!("Global resolution error");
-                error!("{}", CompilerError::ResolutionError(err).getErrorMessage());
+                if let ErrorFormat::Human = self.errorFormat {
+                    error!("Global resolution error");
+                }
+                CompilerError::ResolutionError(err).report(self.errorFormat);
                 return None;
             }
         }
 
-        let mut compiledModule = CompiledModule::empty(self.context);
+        let mut compiledModule = CompiledModule::empty(self.context, self.options);
+        let mut outputVec = Vec::new();
+        let mut error = false;
         for handle in self.threads {
             if let Ok(other) = handle.join() {
-                compiledModule.merge(other?);
+                if let Some((other, mut otherOutputVec)) = other {
+                    compiledModule.merge(other);
+                    outputVec.append(&mut otherOutputVec);
+                } else {
+                    error = true;
+                }
             } else {
                 exit(1);
             }
         }
-        return Some(compiledModule);
+        return if error {
+            None
+        } else {
+            Some((compiledModule, outputVec))
+        };
     }
 }
 
@@ -120,34 +222,42 @@ enum JobManager {
 
 struct CompileJob {
     error: bool,
-    resolverVec: Vec<Resolver>,
+    emitType: EmitType,
+    errorFormat: ErrorFormat,
+    resolverVec: Vec<(usize, Resolver)>,
+    outputVec: Vec<(usize, String)>,
 }
 
 impl CompileJob {
-    fn new(context: Context, jobManager: Arc<Mutex<JobManager>>) -> JoinHandle<Option<CompiledModule>> {
-        return Builder::new().spawn(|| {
+    fn new(context: Context, options: BackendOptions, jobManager: Arc<Mutex<JobManager>>, emitType: EmitType, errorFormat: ErrorFormat) -> JoinHandle<Option<JobResult>> {
+        return Builder::new().spawn(move || {
             return Self {
                 error: false,
+                emitType,
+                errorFormat,
                 resolverVec: Vec::new(),
-            }.start(context, jobManager);
+                outputVec: Vec::new(),
+            }.start(context, options, jobManager);
         }).expect("unable to create thread for job");
     }
 
-    fn start(mut self, context: Context, jobManager: Arc<Mutex<JobManager>>) -> Option<CompiledModule> {
+    fn start(mut self, context: Context, options: BackendOptions, jobManager: Arc<Mutex<JobManager>>) -> Option<JobResult> {
         loop {
             let mut lock = jobManager.lock();
-            if let JobManager::Source(exportTable, source) = lock.deref_mut() {
-                if let Some(source) = source.pop() {
+            if let JobManager::Source(exportTable, sourceVec) = lock.deref_mut() {
+                if let Some(source) = sourceVec.pop() {
+                    // index of source file, used to keep output in order
+                    let index = sourceVec.len();
                     let exportTable = exportTable.to_owned();
                     drop(lock);
-                    self.addSourceFile(source, exportTable);
+                    self.addSourceFile(index, source, exportTable);
                     continue;
                 } else {
                     *lock = JobManager::Complete;
                 }
             }
             debug_assert!(matches!(lock.deref(), JobManager::Complete));
-            return self.getCompiledResult(context);
+            return self.getCompiledResult(context, options);
         }
     }
 
@@ -156,30 +266,64 @@ impl CompileJob {
             Ok(value) => callback(self, value),
             Err(error) => {
                 self.error = true;
-                error!("{}", error.getErrorMessage());
+                error.report(self.errorFormat);
             }
         };
     }
 
-    fn addSourceFile(&mut self, source: String, exportTable: GlobalExportTable) {
-        self.getValue(Compiler::compileFirstStage(exportTable, source), |s, value| s.resolverVec.push(value));
+    fn addSourceFile(&mut self, index: usize, source: String, exportTable: GlobalExportTable) {
+        self.getValue(Compiler::compileFirstStage(exportTable, source, self.emitType), |s, value| {
+            match value {
+                StageResult::Continue(resolver) => s.resolverVec.push((index, resolver)),
+                StageResult::Output(output) => s.outputVec.push((index, output)),
+            }
+        });
     }
 
-    fn getCompiledResult(mut self, context: Context) -> Option<CompiledModule> {
+    fn getCompiledResult(mut self, context: Context, options: BackendOptions) -> Option<JobResult> {
         let mut resolverVec = Vec::new();
         resolverVec.append(&mut self.resolverVec);
 
-        let mut compiledModule = CompiledModule::empty(context.to_owned());
-        for resolver in resolverVec {
-            self.getValue(Compiler::compileSecondStage(context.to_owned(), resolver), |_, value| {
-                compiledModule.merge(value);
+        let mut compiledModule = CompiledModule::empty(context.to_owned(), options.to_owned());
+        for (index, resolver) in resolverVec {
+            self.getValue(Compiler::compileSecondStage(context.to_owned(), options.to_owned(), resolver, self.emitType, self.errorFormat), |s, value| {
+                match value {
+                    StageResult::Continue(value) => compiledModule.merge(value),
+                    StageResult::Output(output) => s.outputVec.push((index, output)),
+                }
             });
         }
 
         return if self.error {
             None
         } else {
-            Some(compiledModule)
+            Some((compiledModule, self.outputVec))
         };
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::num::NonZeroUsize;
+
+    use crate::backend::backendoptions::BackendOptions;
+    use crate::compiler::Compiler;
+    use crate::options::{EmitType, ErrorFormat};
+
+    #[test]
+    fn testStageOutputOrder() {
+        let directory = std::env::temp_dir().join("LLVM-Compiler-test").join("testStageOutputOrder");
+        std::fs::create_dir_all(&directory).unwrap();
+        // path lengths differ from command line order (and some are equal)
+        let sourceVec = ["longest_name.txt", "b.txt", "a.txt", "medium.txt"].iter().enumerate().map(|(index, name)| {
+            let path = directory.join(name);
+            std::fs::write(&path, format!("print({index})\n")).unwrap();
+            path.to_string_lossy().into_owned()
+        }).collect::<Vec<_>>();
+
+        let compiler = Compiler::new(NonZeroUsize::new(4), sourceVec, EmitType::Tokens, ErrorFormat::Human, BackendOptions::default());
+        let output = compiler.getStageOutput().expect("compilation failed");
+        let numberVec = output.lines().filter(|line| line.trim_start().starts_with("Number")).map(|line| line.trim().to_owned()).collect::<Vec<_>>();
+        assert_eq!(vec!["Number \"0\"", "Number \"1\"", "Number \"2\"", "Number \"3\""], numberVec, "{output}");
+    }
+}
@@ -0,0 +1,223 @@
+use crate::module::FileRange;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+    Warning,
+    Note,
+}
+
+impl Severity {
+    pub fn getName(&self) -> &'static str {
+        return match self {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+            Severity::Note => "note",
+        };
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    // identifies the kind of error (name of the error variant)
+    pub code: Option<&'static str>,
+    pub message: String,
+    pub range: Option<FileRange>,
+    pub notes: Vec<Diagnostic>,
+}
+
+impl Diagnostic {
+    pub fn new(severity: Severity, message: String, range: Option<FileRange>) -> Self {
+        return Self {
+            severity,
+            code: None,
+            message,
+            range,
+            notes: Vec::new(),
+        };
+    }
+
+    pub fn error(message: String, range: Option<FileRange>) -> Self {
+        return Self::new(Severity::Error, message, range);
+    }
+
+    pub fn warning(message: String, range: Option<FileRange>) -> Self {
+        return Self::new(Severity::Warning, message, range);
+    }
+
+    pub fn note(message: String, range: Option<FileRange>) -> Self {
+        return Self::new(Severity::Note, message, range);
+    }
+
+    #[must_use]
+    pub fn withCode(mut self, code: &'static str) -> Self {
+        self.code = Some(code);
+        return self;
+    }
+
+    #[must_use]
+    pub fn withNote(mut self, note: Diagnostic) -> Self {
+        self.notes.push(note);
+        return self;
+    }
+
+    // rustc style message, showing the source line with the range underlined
+    //  error: message
+    //   --> path:line:column
+    //    |
+    //  1 | source line
+    //    |        ^^^^
+    pub fn render(&self) -> String {
+        let mut output = format!("{}: {}", self.severity.getName(), self.message);
+
+        if let Some(range) = &self.range {
+            let start = range.getStart();
+            let (line, _) = start.getLineColumn();
+            let lineSource = start.getLine();
+            let gutter = " ".repeat(line.to_string().len());
+
+            // whitespace before range is copied so tabs line up with the source line
+            let source = start.getSourceFile().getSource();
+            let prefix = source[start.getLineStartIndex()..start.getIndex()].chars().map(|c| if c == '\t' { '\t' } else { ' ' }).collect::<String>();
+            // ranges spanning multiple lines are underlined until the end of the first line
+            let underlineEnd = range.getEndIndex().min(start.getLineEndIndex());
+            let underlineLength = source[start.getIndex()..underlineEnd.max(start.getIndex())].chars().count().max(1);
+
+            output += &format!("\n{gutter}--> {start}");
+            output += &format!("\n{gutter} |");
+            output += &format!("\n{line} | {lineSource}");
+            output += &format!("\n{gutter} | {prefix}{}", "^".repeat(underlineLength));
+        }
+
+        for note in &self.notes {
+            output += "\n";
+            output += &note.render();
+        }
+
+        return output;
+    }
+
+    // single line json object
+    // {"severity": ..., "code": ..., "message": ..., "span": {"file", "byteStart", "byteEnd", "line", "column", "endLine", "endColumn"} or null, "notes": [...]}
+    // the end line and column are the position immediately after the range
+    pub fn toJson(&self) -> String {
+        let code = self.code.map(|code| getJsonString(code)).unwrap_or("null".to_owned());
+        let span = if let Some(range) = &self.range {
+            let start = range.getStart();
+            let (line, column) = start.getLineColumn();
+            let (endLine, endColumn) = range.getEnd().getLineColumn();
+            format!("{{\"file\":{},\"byteStart\":{},\"byteEnd\":{},\"line\":{line},\"column\":{column},\"endLine\":{endLine},\"endColumn\":{endColumn}}}", getJsonString(&start.getSourceFile().getFilePath().to_string_lossy()), range.getStartIndex(), range.getEndIndex())
+        } else {
+            format!("null")
+        };
+        let notes = self.notes.iter().map(|note| note.toJson()).collect::<Vec<_>>().join(",");
+        return format!("{{\"severity\":{},\"code\":{code},\"message\":{},\"span\":{span},\"notes\":[{notes}]}}", getJsonString(self.severity.getName()), getJsonString(&self.message));
+    }
+}
+
+fn getJsonString(value: &str) -> String {
+    let mut output = String::from('"');
+    for c in value.chars() {
+        match c {
+            '"' => output += "\\\"",
+            '\\' => output += "\\\\",
+            '\n' => output += "\\n",
+            '\r' => output += "\\r",
+            '\t' => output += "\\t",
+            c if (c as u32) < 0x20 => output += &format!("\\u{:04x}", c as u32),
+            c => output.push(c),
+        }
+    }
+    output.push('"');
+    return output;
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::diagnostic::Diagnostic;
+    use crate::module::{FilePos, FileRange, SourceFile};
+
+    fn getFileRange(source: &str, index: usize, length: usize) -> FileRange {
+        return FileRange::new(FilePos::new(SourceFile::fromSource(PathBuf::from("test.txt"), source.to_owned()), index), length);
+    }
+
+    #[test]
+    fn testRender() {
+        let diagnostic = Diagnostic::error(format!("unknown variable 'y'"), Some(getFileRange("int x = 1\nx = y + 2\n", 14, 1)));
+        assert_eq!("\
+error: unknown variable 'y'
+ --> test.txt:2:5
+  |
+2 | x = y + 2
+  |     ^", diagnostic.render());
+    }
+
+    #[test]
+    fn testRenderWithoutRange() {
+        assert_eq!("warning: message", Diagnostic::warning(format!("message"), None).render());
+    }
+
+    #[test]
+    fn testRenderUnderline() {
+        let source = "\tif value\n\t\tprint(value)\n";
+        // tabs before the range are kept, so that the underline lines up with the source
+        let rendered = Diagnostic::error(format!("error"), Some(getFileRange(source, 4, 5))).render();
+        assert!(rendered.ends_with("1 | \tif value\n  | \t   ^^^^^"), "{rendered}");
+
+        // ranges spanning multiple lines are underlined until the end of the first line
+        let rendered = Diagnostic::error(format!("error"), Some(getFileRange(source, 4, 12))).render();
+        assert!(rendered.ends_with("  | \t   ^^^^^"), "{rendered}");
+
+        // empty ranges are shown with a single caret
+        let rendered = Diagnostic::error(format!("error"), Some(getFileRange(source, 9, 0))).render();
+        assert!(rendered.ends_with("  | \t        ^"), "{rendered}");
+    }
+
+    #[test]
+    fn testRenderNotes() {
+        let source = "int x = 1\nint x = 2\n";
+        let diagnostic = Diagnostic::error(format!("conflicting variable"), Some(getFileRange(source, 14, 1)))
+            .withNote(Diagnostic::note(format!("previously declared here"), Some(getFileRange(source, 4, 1))));
+        assert_eq!("\
+error: conflicting variable
+ --> test.txt:2:5
+  |
+2 | int x = 2
+  |     ^
+note: previously declared here
+ --> test.txt:1:5
+  |
+1 | int x = 1
+  |     ^", diagnostic.render());
+    }
+
+    #[test]
+    fn testJson() {
+        let source = "int x = 1\nx = y + 2\n";
+        let diagnostic = Diagnostic::error(format!("unknown variable 'y'"), Some(getFileRange(source, 14, 1))).withCode("UnknownVariable")
+            .withNote(Diagnostic::note(format!("declared \"here\"\n"), None));
+        assert_eq!(
+            "{\"severity\":\"error\",\"code\":\"UnknownVariable\",\"message\":\"unknown variable 'y'\",\
+\"span\":{\"file\":\"test.txt\",\"byteStart\":14,\"byteEnd\":15,\"line\":2,\"column\":5,\"endLine\":2,\"endColumn\":6},\
+\"notes\":[{\"severity\":\"note\",\"code\":null,\"message\":\"declared \\\"here\\\"\\n\",\"span\":null,\"notes\":[]}]}",
+            diagnostic.toJson()
+        );
+    }
+
+    #[test]
+    fn testJsonEnd() {
+        // end is the position after the range, which may be on a later line
+        let json = Diagnostic::error(format!("multiple lines"), Some(getFileRange("int x = {\n    1\n}\n", 8, 9))).toJson();
+        assert!(json.contains("\"line\":1,\"column\":9,\"endLine\":3,\"endColumn\":2"), "{json}");
+    }
+
+    #[test]
+    fn testJsonEscape() {
+        let json = Diagnostic::warning(format!("tab\tback\\slash\u{1}"), None).toJson();
+        assert!(json.contains("\"message\":\"tab\\tback\\\\slash\\u0001\""), "{json}");
+        assert!(!json.contains('\n'));
+    }
+}
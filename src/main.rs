@@ -1,39 +1,95 @@
 #![allow(non_snake_case)]
 
+use std::path::Path;
 use std::process::exit;
 use std::time::SystemTime;
 
-use log::info;
+use log::{error, info};
 
 use compiler::Compiler;
 
-use crate::backend::link::{checkLinkerPath, getExecutableExtension};
+use crate::backend::link::checkLinkerPath;
+use crate::options::{CompilerOptions, EmitType, USAGE};
 
 pub mod module;
 pub mod ast;
 pub mod resolver;
 pub mod compiler;
 pub mod backend;
+pub mod options;
+pub mod diagnostic;
+
+fn createOutputDirectory(outputPath: &str) {
+    if let Some(parent) = Path::new(outputPath).parent() {
+        std::fs::create_dir_all(parent).expect("failed to create output directory");
+    }
+}
 
 fn main() {
     simple_logger::init().unwrap();
 
-    checkLinkerPath();
+    let options = match CompilerOptions::new(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            error!("{message}");
+            eprintln!("{USAGE}");
+            exit(6);
+        }
+    };
+    if options.help {
+        println!("{USAGE}");
+        return;
+    }
+
+    if let EmitType::Exe = options.emitType {
+        if let Err(error) = checkLinkerPath(&options.backendOptions) {
+            error.report(options.errorFormat);
+            exit(2);
+        }
+    }
 
-    let sourcePathVec = vec!["examples/source.txt".to_owned()];
     let start = SystemTime::now();
-    let compiler = Compiler::new(None, sourcePathVec);
+    let outputPath = options.getOutputPath();
+    let compiler = Compiler::new(options.threadCount, options.sourceVec, options.emitType, options.errorFormat, options.backendOptions);
 
-    if let Some(module) = compiler.getCompiledResult() {
-        std::fs::create_dir_all("output").expect("failed to create output directory");
-        let outputPath = "output/output".to_owned() + getExecutableExtension();
-        module.writeExecutable(&outputPath);
+    let success = if options.emitType.isStageOutput() {
+        if let Some(output) = compiler.getStageOutput() {
+            if let Some(outputPath) = &outputPath {
+                createOutputDirectory(outputPath);
+                std::fs::write(outputPath, output).expect("failed to write output");
+            } else {
+                println!("{output}");
+            }
+            true
+        } else {
+            false
+        }
+    } else if let Some(module) = compiler.getCompiledResult() {
+        let outputPath = outputPath.as_ref().expect("expected output path");
+        createOutputDirectory(outputPath);
+        let result = match options.emitType {
+            EmitType::LlvmIr => module.writeIR(outputPath),
+            EmitType::Bitcode => module.writeBitcode(outputPath),
+            EmitType::Asm => module.writeAssembly(outputPath),
+            EmitType::Obj => module.writeObject(outputPath),
+            EmitType::Exe => module.writeExecutable(outputPath),
+            EmitType::Tokens | EmitType::Ast | EmitType::Resolved => unreachable!(),
+        };
+        if let Err(error) = &result {
+            error.report(options.errorFormat);
+        }
+        result.is_ok()
+    } else {
+        false
+    };
 
-        let end = SystemTime::now();
+    let end = SystemTime::now();
+    if success {
         info!("Compilation completed in {}ms", end.duration_since(start).unwrap().as_millis());
-        info!("Output written to {outputPath}.");
+        if let Some(outputPath) = outputPath {
+            info!("Output written to {outputPath}.");
+        }
     } else {
-        let end = SystemTime::now();
         info!("Compilation failed in {}ms", end.duration_since(start).unwrap().as_millis());
         exit(4);
     }
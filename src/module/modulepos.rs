@@ -75,6 +75,18 @@ impl ModulePos {
     pub fn getRangeWithLength(&self, length: usize) -> ModuleRange {
         return self.module.getModuleRange(self.getTokenIndex()..self.getTokenIndex() + length);
     }
+
+    pub fn getFileRange(&self) -> FileRange {
+        let tokenVec = self.getModule().getTokenVector();
+        if self.tokenIndex == tokenVec.len() {
+            if let Some(lastToken) = tokenVec.last() {
+                // empty range after the last token
+                let lastRange = lastToken.getSourceRange();
+                return FileRange::new(FilePos::new(lastRange.getStart().getSourceFile().to_owned(), lastRange.getEndIndex()), 0);
+            }
+        }
+        return self.getToken().getSourceRange().to_owned();
+    }
 }
 
 #[derive(Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
@@ -124,6 +136,17 @@ impl ModuleRange {
         return &self.getModule().getTokenVector()[self.getStartIndex()..self.getEndIndex()];
     }
 
+    pub fn getFileRange(&self) -> FileRange {
+        let tokens = self.getTokens();
+        return if let (Some(first), Some(last)) = (tokens.first(), tokens.last()) {
+            let start = first.getSourceRange().getStart().to_owned();
+            let length = last.getSourceRange().getEndIndex() - start.getIndex();
+            FileRange::new(start, length)
+        } else {
+            self.startPos.getFileRange()
+        };
+    }
+
     pub fn setStartIndex(&mut self, index: usize) {
         debug_assert!(index <= self.getEndIndex());
         self.startPos.tokenIndex = index;
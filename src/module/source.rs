@@ -1,3 +1,4 @@
+use crate::diagnostic::Diagnostic;
 use crate::module::source::filepos::{FileRange, SourceFile};
 use crate::module::source::sourceparser::SourceParser;
 use crate::module::source::token::Token;
@@ -27,6 +28,10 @@ impl ParseError {
         return &self.errorMessage;
     }
 
+    pub fn getDiagnostic(&self) -> Diagnostic {
+        return Diagnostic::error(self.errorMessage.to_owned(), Some(self.fileRange.to_owned())).withCode("ParseError");
+    }
+
     pub fn getDisplayMessage(&self) -> String {
         const MAX_SOURCE_DISPLAY_LENGTH: usize = 100;
         let mut relevantSource = self.fileRange.getSourceInRange();
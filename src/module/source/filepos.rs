@@ -2,7 +2,7 @@ use std::fmt::{Debug, Display, Formatter};
 use std::fs::File;
 use std::io::Read;
 use std::path::{Path, PathBuf};
-use std::rc::Rc;
+use std::sync::Arc;
 
 use anyhow::Result;
 
@@ -25,19 +25,19 @@ impl FileInfo {
 
 #[derive(Clone)]
 pub struct SourceFile {
-    fileInfo: Rc<FileInfo>,
+    fileInfo: Arc<FileInfo>,
 }
 
 impl SourceFile {
     pub fn new(path: PathBuf) -> Result<Self> {
         return Ok(Self {
-            fileInfo: Rc::new(FileInfo::new(path)?),
+            fileInfo: Arc::new(FileInfo::new(path)?),
         });
     }
 
     pub fn fromSource(fullPath: PathBuf, fileSource: String) -> Self {
         return Self {
-            fileInfo: Rc::new(FileInfo {
+            fileInfo: Arc::new(FileInfo {
                 fullPath,
                 fileSource,
             }),
@@ -79,6 +79,35 @@ impl FilePos {
     pub fn getSourceFile(&self) -> &SourceFile {
         return &self.sourceFile;
     }
+
+    // line and column, starting at 1
+    pub fn getLineColumn(&self) -> (usize, usize) {
+        let source = &self.sourceFile.getSource()[..self.index];
+        let line = source.matches('\n').count() + 1;
+        let column = source[self.getLineStartIndex()..].chars().count() + 1;
+        return (line, column);
+    }
+
+    pub fn getLineStartIndex(&self) -> usize {
+        return self.sourceFile.getSource()[..self.index].rfind('\n').map(|index| index + 1).unwrap_or(0);
+    }
+
+    pub fn getLineEndIndex(&self) -> usize {
+        let source = self.sourceFile.getSource();
+        return source[self.index..].find('\n').map(|index| self.index + index).unwrap_or(source.len());
+    }
+
+    // source of the line containing this position (excluding line terminator)
+    pub fn getLine(&self) -> &str {
+        return self.sourceFile.getSource()[self.getLineStartIndex()..self.getLineEndIndex()].trim_end_matches('\r');
+    }
+}
+
+impl Display for FilePos {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (line, column) = self.getLineColumn();
+        return write!(f, "{}:{line}:{column}", self.sourceFile.getFilePath().display());
+    }
 }
 
 #[derive(Clone)]
@@ -89,7 +118,7 @@ pub struct FileRange {
 
 impl Display for FileRange {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        return write!(f, "{:?}:{} (through {})", self.start.sourceFile.fileInfo.fullPath, self.getStartIndex(), self.getEndIndex());
+        return write!(f, "{}", self.start);
     }
 }
 
@@ -113,6 +142,10 @@ impl FileRange {
         return &self.start.sourceFile.fileInfo.fileSource[self.getStartIndex()..self.getEndIndex()];
     }
 
+    pub fn getStart(&self) -> &FilePos {
+        return &self.start;
+    }
+
     pub fn getStartIndex(&self) -> usize {
         return self.start.getIndex();
     }
@@ -120,4 +153,56 @@ impl FileRange {
     pub fn getEndIndex(&self) -> usize {
         return self.getStartIndex() + self.length;
     }
+
+    // position immediately after the range
+    pub fn getEnd(&self) -> FilePos {
+        return FilePos::new(self.start.sourceFile.to_owned(), self.getEndIndex());
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::module::{FilePos, SourceFile};
+
+    fn getFilePos(source: &str, index: usize) -> FilePos {
+        return FilePos::new(SourceFile::fromSource(PathBuf::from("test.txt"), source.to_owned()), index);
+    }
+
+    #[test]
+    fn testLineColumn() {
+        let source = "first\nsecond line\n\nlast";
+        assert_eq!((1, 1), getFilePos(source, 0).getLineColumn());
+        assert_eq!((1, 6), getFilePos(source, 5).getLineColumn());
+        assert_eq!((2, 1), getFilePos(source, 6).getLineColumn());
+        assert_eq!((2, 8), getFilePos(source, 13).getLineColumn());
+        assert_eq!((3, 1), getFilePos(source, 18).getLineColumn());
+        assert_eq!((4, 5), getFilePos(source, source.len()).getLineColumn());
+        // columns count characters rather than bytes
+        assert_eq!((1, 3), getFilePos("ää x", 4).getLineColumn());
+    }
+
+    #[test]
+    fn testLineBounds() {
+        let source = "first\nsecond line\r\n\nlast";
+        let pos = getFilePos(source, 9);
+        assert_eq!(6, pos.getLineStartIndex());
+        assert_eq!(18, pos.getLineEndIndex());
+        assert_eq!("second line", pos.getLine());
+
+        // empty line
+        let pos = getFilePos(source, 19);
+        assert_eq!(19, pos.getLineStartIndex());
+        assert_eq!(19, pos.getLineEndIndex());
+        assert_eq!("", pos.getLine());
+
+        // last line has no line terminator
+        let pos = getFilePos(source, 21);
+        assert_eq!(20, pos.getLineStartIndex());
+        assert_eq!(source.len(), pos.getLineEndIndex());
+        assert_eq!("last", pos.getLine());
+
+        assert_eq!("test.txt:2:4", getFilePos(source, 9).to_string());
+    }
 }
@@ -484,6 +484,19 @@ impl SourceParser {
                     let number = isInnerNumberChar(character) || outerNumber;
                     let outerIdentifier = isOuterIdentifierCharacter(character);
 
+                    // ".." following a number is a range, not part of the number (e.g. 0..10)
+                    if character == '.' && self.peekNextChar('.') && !self.isFirstCharacterInBasicToken() && self.basicTokenOption.isOptionSet(Number) {
+                        self.addBasicTokenExcludeLastChar(false)?;
+                    }
+
+                    // a second "." ends the number, so that nested tuple access is not a single number (e.g. t.1.0.1 is t . 1.0 . 1)
+                    if character == '.' && !self.isFirstCharacterInBasicToken() && self.basicTokenOption.isOptionSet(Number) && {
+                        let number = &self.getBasicTokenSource()[..self.getBasicTokenSource().len() - 1];
+                        number.starts_with(|c: char| c.is_ascii_digit()) && number.contains('.')
+                    } {
+                        self.addBasicTokenExcludeLastChar(false)?;
+                    }
+
                     if (operator && self.basicTokenOption.isOptionSet(Word)) || (!operator && self.basicTokenOption.isOptionSet(Operator) && self.lastTokenStart < lastCharacterIndex) {
                         self.addBasicTokenExcludeLastChar(false)?;
                     }
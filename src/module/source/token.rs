@@ -65,10 +65,16 @@ pub enum Operator {
     Increment,
     // --
     Decrement,
+    // ++value (never lexed, increment is converted by position in the operator expression)
+    PrefixIncrement,
+    // --value (never lexed, decrement is converted by position in the operator expression)
+    PrefixDecrement,
     // !
     Not,
     // .
     Dot,
+    // value[index] (never lexed, square parenthesis following an operand is converted in the operator expression)
+    Index,
     // ..
     Range,
     // ...
@@ -83,6 +89,8 @@ pub enum Operator {
     Plus,
     // -
     Minus,
+    // -value (never lexed, minus is converted by position in the operator expression)
+    Negate,
     // *
     Mult,
     // /
@@ -131,15 +139,20 @@ impl Operator {
             Operator::Range => 4,
             Operator::Plus | Operator::Minus => 5,
             Operator::Mult | Operator::Div | Operator::Mod => 6,
-            Operator::ErrorPropagation | Operator::Dot | Operator::Cast | Operator::Not | Operator::Increment | Operator::Decrement => 10,
+            // prefix operators bind tighter than casts (-a as float is (-a) as float)
+            Operator::Cast => 8,
+            Operator::Not | Operator::Negate | Operator::PrefixIncrement | Operator::PrefixDecrement => 9,
+            Operator::ErrorPropagation | Operator::Dot | Operator::Index | Operator::Increment | Operator::Decrement => 10,
             Operator::Ellipsis | Operator::Colon => 20,
         };
     }
 
     pub fn getOperands(&self) -> usize {
         return match self {
-            Operator::Increment | Operator::Decrement | Operator::Not | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation | Operator::Cast => 1,
-            Operator::Dot | Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div | Operator::Mod | Operator::PlusAssign | Operator::MinusAssign | Operator::MultAssign | Operator::DivAssign | Operator::ModAssign | Operator::And | Operator::Or | Operator::Greater | Operator::Less | Operator::GreaterEq | Operator::LessEq | Operator::CompareEq | Operator::CompareNotEq | Operator::AssignEq => 2,
+            Operator::Increment | Operator::Decrement | Operator::PrefixIncrement | Operator::PrefixDecrement | Operator::Not | Operator::Negate | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation => 1,
+            // value as type
+            Operator::Cast => 2,
+            Operator::Dot | Operator::Index | Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div | Operator::Mod | Operator::PlusAssign | Operator::MinusAssign | Operator::MultAssign | Operator::DivAssign | Operator::ModAssign | Operator::And | Operator::Or | Operator::Greater | Operator::Less | Operator::GreaterEq | Operator::LessEq | Operator::CompareEq | Operator::CompareNotEq | Operator::AssignEq => 2,
             Operator::Range => 2,
         };
     }
@@ -148,8 +161,11 @@ impl Operator {
         return match self {
             Operator::Increment => "++",
             Operator::Decrement => "--",
+            Operator::PrefixIncrement => "++",
+            Operator::PrefixDecrement => "--",
             Operator::Not => "!",
             Operator::Dot => ".",
+            Operator::Index => "[]",
             Operator::Range => "..",
             Operator::Ellipsis => "...",
             Operator::Colon => ":",
@@ -157,6 +173,7 @@ impl Operator {
             Operator::Cast => "as",
             Operator::Plus => "+",
             Operator::Minus => "-",
+            Operator::Negate => "-",
             Operator::Mult => "*",
             Operator::Div => "/",
             Operator::Mod => "%",
@@ -180,7 +197,22 @@ impl Operator {
     pub fn isKeywordOperator(&self) -> bool {
         return match self {
             Operator::And | Operator::Or | Operator::Cast => true,
-            Operator::Increment | Operator::Decrement | Operator::Not | Operator::Dot | Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation | Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div | Operator::Mod | Operator::PlusAssign | Operator::MinusAssign | Operator::MultAssign | Operator::DivAssign | Operator::ModAssign | Operator::Greater | Operator::Less | Operator::GreaterEq | Operator::LessEq | Operator::CompareEq | Operator::CompareNotEq | Operator::AssignEq => false,
+            Operator::Increment | Operator::Decrement | Operator::PrefixIncrement | Operator::PrefixDecrement | Operator::Not | Operator::Negate | Operator::Dot | Operator::Index | Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation | Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div | Operator::Mod | Operator::PlusAssign | Operator::MinusAssign | Operator::MultAssign | Operator::DivAssign | Operator::ModAssign | Operator::Greater | Operator::Less | Operator::GreaterEq | Operator::LessEq | Operator::CompareEq | Operator::CompareNotEq | Operator::AssignEq => false,
+        };
+    }
+
+    // prefix operators are applied to the operand which follows them, all other unary operators are postfix
+    pub fn isPrefix(&self) -> bool {
+        return matches!(self, Operator::Not | Operator::Negate | Operator::PrefixIncrement | Operator::PrefixDecrement);
+    }
+
+    // operator used in place of this operator if there is no left operand
+    pub fn getPrefixOperator(&self) -> Option<Operator> {
+        return match self {
+            Operator::Minus => Some(Operator::Negate),
+            Operator::Increment => Some(Operator::PrefixIncrement),
+            Operator::Decrement => Some(Operator::PrefixDecrement),
+            _ => None,
         };
     }
 
@@ -201,7 +233,8 @@ impl Operator {
         static MAP: Lazy<HashMap<&'static str, Operator>> = Lazy::new(|| {
             let mut map = HashMap::new();
             for operator in Operator::iter() {
-                if !operator.isKeywordOperator() {
+                // prefix operators share characters with the operator they are converted from, index is lexed as a parenthesis
+                if !operator.isKeywordOperator() && operator != Operator::Index && Operator::iter().all(|other| other.getPrefixOperator() != Some(operator)) {
                     map.insert(operator.getCharacters(), operator);
                 }
             }
@@ -233,7 +266,9 @@ pub enum Keyword {
     Else,
     While,
     For,
+    In,
     Loop,
+    Match,
     Break,
     Continue,
     Return,
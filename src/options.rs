@@ -0,0 +1,165 @@
+use std::num::NonZeroUsize;
+use std::str::FromStr;
+
+use strum_macros::EnumString;
+
+use crate::backend::backendoptions::{BackendOptions, OptimizationLevel};
+use crate::backend::link::LinkerFlavor;
+
+#[derive(EnumString)]
+#[strum(serialize_all = "kebab-case")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EmitType {
+    Tokens,
+    Ast,
+    Resolved,
+    LlvmIr,
+    Bitcode,
+    Asm,
+    Obj,
+    Exe,
+}
+
+impl EmitType {
+    // emit types which are written before reaching the backend
+    pub fn isStageOutput(&self) -> bool {
+        return match self {
+            EmitType::Tokens | EmitType::Ast | EmitType::Resolved => true,
+            EmitType::LlvmIr | EmitType::Bitcode | EmitType::Asm | EmitType::Obj | EmitType::Exe => false,
+        };
+    }
+
+    pub fn getExtension(&self, flavor: LinkerFlavor) -> &'static str {
+        return match self {
+            EmitType::Tokens | EmitType::Ast | EmitType::Resolved => ".txt",
+            EmitType::LlvmIr => ".ll",
+            EmitType::Bitcode => ".bc",
+            EmitType::Asm => ".s",
+            EmitType::Obj => flavor.getObjectExtension(),
+            EmitType::Exe => flavor.getExecutableExtension(),
+        };
+    }
+}
+
+#[derive(EnumString)]
+#[strum(serialize_all = "lowercase")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ErrorFormat {
+    // rendered diagnostics, showing the relevant source
+    Human,
+    // one json object per diagnostic, per line
+    Json,
+}
+
+pub const USAGE: &str = "\
+Usage: LLVM-Compiler [options] <source>...
+
+Options:
+    -o <path>              write output to <path>
+    -j, --threads <count>  number of threads used for compilation
+    --emit <type>          output type (tokens|ast|resolved|llvm-ir|bitcode|asm|obj|exe)
+    --error-format <format>
+                           format of reported errors (human|json)
+    --target <triple>      target triple to generate code for (defaults to the host triple)
+                           executables are linked with lld-link (windows), ld64.lld (macos),
+                           or through a C compiler driver for other targets (cc for the host
+                           triple, clang -fuse-ld=lld when --target is given), which must be
+                           available on path
+    --linker <program>     program used to link executables, instead of the default for the
+                           target (a C compiler driver for targets other than windows and macos)
+    -O0, -O1, -O2, -O3, -Os
+                           optimization level (defaults to -O0)
+    --target-cpu <cpu>     cpu to generate code for (\"native\" for the host cpu and features,
+                           unavailable when cross compiling)
+    --target-features <features>
+                           cpu features to enable/disable (e.g. \"+avx2,-sse4.1\"), applied
+                           after host features when using \"native\"
+    --trap-division-by-zero
+                           trap on integer division or remainder by zero, or overflowing
+                           (minimum int divided by -1)
+    --no-bounds-checks     do not check array and string indices against the length
+    -h, --help             print this message";
+
+#[derive(Debug)]
+pub struct CompilerOptions {
+    pub sourceVec: Vec<String>,
+    pub outputPath: Option<String>,
+    pub threadCount: Option<NonZeroUsize>,
+    pub emitType: EmitType,
+    pub errorFormat: ErrorFormat,
+    pub backendOptions: BackendOptions,
+    pub help: bool,
+}
+
+impl CompilerOptions {
+    pub fn new(args: impl IntoIterator<Item=String>) -> Result<Self, String> {
+        let mut options = Self {
+            sourceVec: Vec::new(),
+            outputPath: None,
+            threadCount: None,
+            emitType: EmitType::Exe,
+            errorFormat: ErrorFormat::Human,
+            backendOptions: BackendOptions::default(),
+            help: false,
+        };
+
+        let mut args = args.into_iter();
+        while let Some(arg) = args.next() {
+            // options may be provided as either "--name value" or "--name=value"
+            let (name, mut value) = match arg.split_once('=') {
+                Some((name, value)) if name.starts_with("--") => (name.to_owned(), Some(value.to_owned())),
+                _ => (arg, None),
+            };
+            let mut getValue = || value.take().or_else(|| args.next()).ok_or_else(|| format!("expected value for option {name}"));
+
+            match name.as_str() {
+                "-h" | "--help" => options.help = true,
+                "-o" => options.outputPath = Some(getValue()?),
+                "-j" | "--threads" => {
+                    let count = getValue()?;
+                    options.threadCount = Some(NonZeroUsize::from_str(&count).map_err(|_| format!("invalid thread count \"{count}\""))?);
+                }
+                "-O0" | "-O1" | "-O2" | "-O3" | "-Os" => options.backendOptions.optimizationLevel = OptimizationLevel::from_str(&name[1..]).unwrap(),
+                "--target" => options.backendOptions.targetTriple = Some(getValue()?),
+                "--target-cpu" => options.backendOptions.targetCpu = getValue()?,
+                "--target-features" => options.backendOptions.targetFeatures = getValue()?,
+                "--linker" => options.backendOptions.linker = Some(getValue()?),
+                "--trap-division-by-zero" => options.backendOptions.divisionByZeroTrap = true,
+                "--no-bounds-checks" => options.backendOptions.boundsChecks = false,
+                "--emit" => {
+                    let emitType = getValue()?;
+                    options.emitType = EmitType::from_str(&emitType).map_err(|_| format!("unknown emit type \"{emitType}\""))?;
+                }
+                "--error-format" => {
+                    let errorFormat = getValue()?;
+                    options.errorFormat = ErrorFormat::from_str(&errorFormat).map_err(|_| format!("unknown error format \"{errorFormat}\""))?;
+                }
+                _ if name.starts_with('-') => return Err(format!("unknown option {name}")),
+                _ => options.sourceVec.push(name.to_owned()),
+            }
+
+            if value.is_some() {
+                return Err(format!("option {name} does not take a value"));
+            }
+        }
+
+        if options.sourceVec.is_empty() && !options.help {
+            return Err(format!("no source files provided"));
+        }
+
+        return Ok(options);
+    }
+
+    // returns None if output should be written to stdout
+    pub fn getOutputPath(&self) -> Option<String> {
+        if self.outputPath.is_some() {
+            return self.outputPath.to_owned();
+        }
+        return if self.emitType.isStageOutput() {
+            None
+        } else {
+            let flavor = LinkerFlavor::new(&self.backendOptions.getTargetTriple());
+            Some("output/output".to_owned() + self.emitType.getExtension(flavor))
+        };
+    }
+}
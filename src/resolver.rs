@@ -14,33 +14,52 @@ use crate::ast::symbol::block::BlockSym;
 use crate::ast::symbol::expr::Expr;
 use crate::ast::symbol::expr::functioncall::FunctionCallExpr;
 use crate::ast::symbol::function::FunctionDefinitionSym;
-use crate::module::modulepos::ModulePos;
+use crate::ast::symbol::looptype::label::Label;
+use crate::module::FileRange;
+use crate::module::modulepos::ModuleRange;
 use crate::module::Operator;
 use crate::resolver::exporttable::completeexporttable::CompleteExportTable;
 use crate::resolver::exporttable::GlobalExportTable;
 use crate::resolver::exporttable::incompleteexporttable::IncompleteExportTable;
 use crate::resolver::function::Function;
 use crate::resolver::resolutionerror::ResolutionError;
+use crate::resolver::resolutionwarning::ResolutionWarning;
+use crate::resolver::resolvedast::arraylength::ArrayLength;
+use crate::resolver::resolvedast::breakstatement::BreakStatement;
+use crate::resolver::resolvedast::continuestatement::ContinueStatement;
+use crate::resolver::resolvedast::forstatement::ForStatement;
 use crate::resolver::resolvedast::functioncall::FunctionCall;
 use crate::resolver::resolvedast::ifstatement::IfStatement;
+use crate::resolver::resolvedast::literalarray::LiteralArray;
+use crate::resolver::resolvedast::literalenum::LiteralEnum;
+use crate::resolver::resolvedast::literaltuple::LiteralTuple;
+use crate::resolver::resolvedast::loopstatement::LoopStatement;
+use crate::resolver::resolvedast::matchstatement::{MatchCase, MatchStatement};
 use crate::resolver::resolvedast::printstatement::PrintStatement;
 use crate::resolver::resolvedast::ResolvedAST;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::resolvedast::resolvedfunctiondefinition::ResolvedFunctionDefinition;
+use crate::resolver::resolvedast::resolvedindex::ResolvedIndex;
+use crate::resolver::resolvedast::resolvedcast::ResolvedCast;
 use crate::resolver::resolvedast::resolvedoperator::ResolvedOperator;
 use crate::resolver::resolvedast::resolvedproperty::ResolvedProperty;
 use crate::resolver::resolvedast::resolvedscope::ResolvedScope;
 use crate::resolver::resolvedast::resolvedvariable::ResolvedVariable;
 use crate::resolver::resolvedast::returnstatement::ReturnStatement;
 use crate::resolver::resolvedast::statement::Statement;
+use crate::resolver::resolvedast::tupleinitialize::TupleInitialize;
 use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+use crate::resolver::resolvedast::variableinitialize::VariableInitialize;
 use crate::resolver::resolvedast::whilestatement::WhileStatement;
+use crate::resolver::typeinfo::array::ArrayType;
+use crate::resolver::typeinfo::error::ERROR_TYPE;
 use crate::resolver::typeinfo::primitive::boolean::BOOLEAN_TYPE;
 use crate::resolver::typeinfo::primitive::character::CHARACTER_TYPE;
 use crate::resolver::typeinfo::primitive::float::FLOAT_TYPE;
 use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
 use crate::resolver::typeinfo::string::STRING_TYPE;
-use crate::resolver::typeinfo::Type;
+use crate::resolver::typeinfo::tuple::TupleType;
+use crate::resolver::typeinfo::{Type, TypeInfo};
 use crate::resolver::typeinfo::void::VOID_TYPE;
 
 pub mod exporttable;
@@ -48,6 +67,7 @@ pub mod resolvedast;
 pub mod typeinfo;
 pub mod function;
 pub mod resolutionerror;
+pub mod resolutionwarning;
 pub mod typefunctioninfo;
 
 pub struct Resolver {
@@ -85,15 +105,21 @@ impl Resolver {
     }
 
     // resolve symbols
-    pub fn getResolvedAST(self) -> Result<ResolvedAST, Vec<ResolutionError>> {
+    // warnings are provided regardless of whether resolution succeeded
+    pub fn getResolvedAST(self) -> (Result<ResolvedAST, Vec<ResolutionError>>, Vec<ResolutionWarning>) {
+        let exportTable = match self.exportTable.getCompleteExportTableBlocking() {
+            Some(exportTable) => exportTable,
+            None => return (Err(vec![]), vec![]),
+        };
         let mut resolutionHandler = ResolutionHandler {
-            exportTable: self.exportTable.getCompleteExportTableBlocking().ok_or_else(|| vec![])?,
+            exportTable,
             resolver: vec![Rc::new(TopLevelResolver)],
             errorVec: Vec::new(),
+            warningVec: Vec::new(),
             scope: Scope::root(),
         };
 
-        return if let Some(statementVec) = resolutionHandler.resolveAll(true, self.ast.getSymbols().iter()) {
+        let result = if let Some(statementVec) = resolutionHandler.resolveAll(true, self.ast.getSymbols().iter()) {
             debug_assert!(resolutionHandler.errorVec.is_empty());
             Ok(ResolvedAST::new(ResolvedScope {
                 statementVec,
@@ -102,6 +128,7 @@ impl Resolver {
             debug_assert!(!resolutionHandler.errorVec.is_empty(), "compilation failed but no errors provided");
             Err(resolutionHandler.errorVec)
         };
+        return (result, resolutionHandler.warningVec);
     }
 }
 
@@ -121,32 +148,37 @@ struct TopLevelResolver;
 #[derive(Debug)]
 struct FunctionResolver(Function);
 
+// resolves break and continue statements targeting the loop
+// other symbols are resolved by the parent (the resolver of the symbol containing the loop)
+#[derive(Debug)]
+struct LoopResolver {
+    label: Option<String>,
+    id: usize,
+    parent: Rc<dyn ResolverType>,
+}
+
 struct Scope {
     parent: Option<Box<Scope>>,
-    variableMap: HashMap<String, ResolvedVariable>,
+    // variables with the range of their declaration
+    variableMap: HashMap<String, (ResolvedVariable, FileRange)>,
 }
 
 struct ResolutionHandler {
     resolver: Vec<Rc<dyn ResolverType>>,
     errorVec: Vec<ResolutionError>,
+    warningVec: Vec<ResolutionWarning>,
     exportTable: Arc<CompleteExportTable>,
     scope: Scope,
 }
 
 impl TopLevelResolver {
-    fn checkReturnStatement(errorVec: &mut Vec<ResolutionError>, returnType: Type, statementVec: &Vec<Statement>) {
-        if returnType != VOID_TYPE.to_owned() {
-            // last statement must be return statement
-            if let Some(last) = statementVec.last() {
-                if let Statement::Return(v) = last {
-                    // should have been checked earlier
-                    debug_assert_eq!(v.expr.as_ref().map(|v| v.getExpressionType()), Some(returnType));
-                    // success
-                    return;
-                }
-            }
-            errorVec.push(ResolutionError::MissingReturn(format!("function missing return (non-void functions must always have return statement as last statement in function).", )))
+    // returns false if an error was reported
+    fn checkReturnStatement(errorVec: &mut Vec<ResolutionError>, range: FileRange, returnType: Type, statementVec: &Vec<Statement>) -> bool {
+        if returnType != VOID_TYPE.to_owned() && !Statement::allPathsReturn(statementVec) {
+            errorVec.push(ResolutionError::MissingReturn(range, format!("function missing return (non-void functions must return a value on every path)")));
+            return false;
         }
+        return true;
     }
 
     fn resolveFunction(&self, function: Function, resolutionHandler: &mut ResolutionHandler, functionDefinition: &FunctionDefinitionSym) -> Option<ResolvedFunctionDefinition> {
@@ -156,14 +188,23 @@ impl TopLevelResolver {
         fn resolveFunctionInner(function: &Function, resolutionHandler: &mut ResolutionHandler, functionDefinition: &FunctionDefinitionSym) -> Option<(ResolvedScope, Vec<usize>)> {
             let mut parameterVec = Vec::new();
 
-            for parameter in &function.parameters {
-                parameterVec.push(resolutionHandler.scope.declareVariable(&parameter.name, parameter.ty.to_owned(), &mut resolutionHandler.errorVec)?.id);
+            // methods have an implicit self parameter, which does not appear in the definition
+            let implicitParameters = function.parameters.len() - functionDefinition.parameters.len();
+            for (index, parameter) in function.parameters.iter().enumerate() {
+                let range = if index < implicitParameters {
+                    functionDefinition.functionName.getFileRange()
+                } else {
+                    functionDefinition.parameters[index - implicitParameters].parameterName.getFileRange()
+                };
+                parameterVec.push(resolutionHandler.scope.declareVariable(&parameter.name, range, parameter.ty.to_owned(), &mut resolutionHandler.errorVec)?.id);
             }
             resolutionHandler.pushResolver(FunctionResolver(function.to_owned()));
             let resolvedScope = resolutionHandler.resolveBlock(&functionDefinition.functionBlock);
             resolutionHandler.popResolver();
             let resolvedScope = resolvedScope?;
-            TopLevelResolver::checkReturnStatement(&mut resolutionHandler.errorVec, function.returnType.to_owned(), &resolvedScope.statementVec);
+            if !TopLevelResolver::checkReturnStatement(&mut resolutionHandler.errorVec, functionDefinition.functionName.getFileRange(), function.returnType.to_owned(), &resolvedScope.statementVec) {
+                return None;
+            }
             return Some((resolvedScope, parameterVec));
         }
 
@@ -184,22 +225,32 @@ impl ResolverType for TopLevelResolver {
         return match symbol {
             Symbol::ClassDefinition(symbol) => {
                 let mut resolvedVec = Vec::new();
-                let classType = resolutionHandler.exportTable.getExportedType(&symbol.name.getToken().getSourceRange().getSourceInRange()).expect("unable to find type defined by class");
+                let classType = resolutionHandler.exportTable.getExportedType(&symbol.name.getToken().getSourceRange().getSourceInRange(), &symbol.name.getFileRange()).expect("unable to find type defined by class");
                 let functionInfo = resolutionHandler.exportTable.getTypeFunctionInfo(classType.to_owned());
 
+                let mut failed = false;
+
+                // remaining methods are still resolved after a failure, so that all errors are reported
                 for functionDefinition in &symbol.methods {
                     let functionName = functionDefinition.functionName.getToken().getSourceRange().getSourceInRange();
                     let function = functionInfo.getFunction(functionName).expect("unable to find function for class definition");
                     if let Some(resolved) = self.resolveFunction(function, resolutionHandler, functionDefinition) {
                         resolvedVec.push(Statement::FunctionDefinition(resolved));
                     } else {
-                        return Resolution::Err;
+                        failed = true;
                     }
                 }
-                Resolution::Ok(Statement::Multiple(resolvedVec))
+
+                if failed {
+                    Resolution::Err
+                } else {
+                    Resolution::Ok(Statement::Multiple(resolvedVec))
+                }
             }
+            // enums are exported types, and do not produce any code
+            Symbol::EnumDefinition(_) => Resolution::Ok(Statement::Multiple(Vec::new())),
             Symbol::FunctionDefinition(functionDefinition) => {
-                let function = resolutionHandler.exportTable.getExportedFunction(functionDefinition.functionName.getToken().getSourceRange().getSourceInRange()).expect("unable to find function for definition");
+                let function = resolutionHandler.exportTable.getExportedFunction(functionDefinition.functionName.getToken().getSourceRange().getSourceInRange(), &functionDefinition.functionName.getFileRange()).expect("unable to find function for definition");
                 return if let Some(resolved) = self.resolveFunction(function, resolutionHandler, functionDefinition) {
                     Resolution::Ok(Statement::FunctionDefinition(resolved))
                 } else {
@@ -234,7 +285,8 @@ impl ResolverType for FunctionResolver {
                 return if ty == self.0.returnType {
                     Resolution::Ok(statement)
                 } else {
-                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(self.0.returnType.to_owned(), ty, format!("mismatched return type")));
+                    let range = symbol.value.as_ref().map(|expr| expr.getRange()).unwrap_or(&symbol.range).getFileRange();
+                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(range, self.0.returnType.to_owned(), ty, format!("mismatched return type")));
                     Resolution::Err
                 };
             }
@@ -243,24 +295,76 @@ impl ResolverType for FunctionResolver {
     }
 }
 
+impl LoopResolver {
+    // whether break/continue with the label should target this loop
+    fn isTarget(&self, label: &Option<Label>) -> bool {
+        return match label {
+            Some(label) => self.label.as_deref() == Some(label.identifier.getToken().getSourceRange().getSourceInRange()),
+            None => true,
+        };
+    }
+}
+
+impl ResolverType for LoopResolver {
+    fn resolve(&self, resolutionHandler: &mut ResolutionHandler, symbol: &Symbol) -> Resolution {
+        return match symbol {
+            Symbol::Break(symbol) if self.isTarget(&symbol.label) => Resolution::Ok(Statement::Break(BreakStatement {
+                loopId: self.id,
+            })),
+            Symbol::Continue(symbol) if self.isTarget(&symbol.label) => Resolution::Ok(Statement::Continue(ContinueStatement {
+                loopId: self.id,
+            })),
+            _ => self.parent.resolve(resolutionHandler, symbol),
+        };
+    }
+}
+
 impl ResolutionHandler {
+    // symbols following a failed symbol are still resolved, so that all errors are reported
     fn resolveAll<'a>(&mut self, global: bool, symbols: impl Iterator<Item = &'a Symbol>) -> Option<Vec<Statement>> {
         let mut statementVec = Vec::new();
+        let mut failed = false;
+        let mut terminated = false;
+        let mut reportedUnreachable = false;
         for symbol in symbols {
             if let Some(statement) = self.resolve(symbol, global) {
+                // only the first unreachable statement is reported
+                // functions and classes are declarations, rather than code that is run
+                if terminated && !reportedUnreachable && !matches!(statement, Statement::FunctionDefinition(_) | Statement::Multiple(_)) {
+                    self.warningVec.push(ResolutionWarning::UnreachableCode(symbol.getSymbolType().getRange().getFileRange()));
+                    reportedUnreachable = true;
+                }
+                terminated |= statement.isTerminating();
                 statementVec.push(statement);
             } else {
                 debug_assert!(!self.errorVec.is_empty(), "failed to resolve symbol {symbol:?} but no error provided");
-                return None;
+                failed = true;
             }
         }
-        return Some(statementVec);
+        return if failed {
+            None
+        } else {
+            Some(statementVec)
+        };
     }
 
     fn pushResolver(&mut self, resolver: impl ResolverType) {
         self.resolver.push(Rc::new(resolver));
     }
 
+    // returns id of the loop
+    fn pushLoopResolver(&mut self, label: Option<&Label>) -> usize {
+        static NEXT_LOOP_ID: AtomicUsize = AtomicUsize::new(0);
+
+        let id = NEXT_LOOP_ID.fetch_add(1, Ordering::Relaxed);
+        self.pushResolver(LoopResolver {
+            label: label.map(|label| label.identifier.getToken().getSourceRange().getSourceInRange().to_owned()),
+            id,
+            parent: self.resolver.last().unwrap().to_owned(),
+        });
+        return id;
+    }
+
     fn popResolver(&mut self) {
         debug_assert!(self.resolver.len() > 1);
         self.resolver.pop();
@@ -295,6 +399,51 @@ impl ResolutionHandler {
         Some(getResolvedExpression(self, expr, global, Box::new(|_, resolved| resolved))?)
     }
 
+    fn resolveCondition(&mut self, condition: &Expr, global: bool, message: &str) -> Option<ResolvedExpr> {
+        let expr = self.resolveExpr(condition, global)?;
+        return if expr.getExpressionType() == BOOLEAN_TYPE.to_owned() {
+            Some(expr)
+        } else {
+            self.errorVec.push(ResolutionError::ExpectedType(condition.getRange().getFileRange(), BOOLEAN_TYPE.to_owned(), expr.getExpressionType(), message.to_owned()));
+            None
+        };
+    }
+
+    // start..end, where both bounds are integers
+    fn resolveRange(&mut self, iterable: &Expr, global: bool) -> Option<(ResolvedExpr, ResolvedExpr)> {
+        let expr = match iterable {
+            Expr::Operator(expr) if matches!(expr.operator, Operator::Range) => expr,
+            _ => {
+                self.errorVec.push(ResolutionError::Unsupported(iterable.getRange().getFileRange(), format!("for loops can only iterate over an integer range (e.g. 0..10)")));
+                return None;
+            }
+        };
+
+        let start = self.resolveExpr(&expr.operands[0], global);
+        let end = self.resolveExpr(&expr.operands[1], global);
+        let (start, end) = (start?, end?);
+        let mut failed = false;
+        for (index, bound) in [&start, &end].into_iter().enumerate() {
+            if bound.getExpressionType() != INTEGER_TYPE.to_owned() {
+                self.errorVec.push(ResolutionError::ExpectedType(expr.operands[index].getRange().getFileRange(), INTEGER_TYPE.to_owned(), bound.getExpressionType(), format!("expected integer bound for range")));
+                failed = true;
+            }
+        }
+        return if failed {
+            None
+        } else {
+            Some((start, end))
+        };
+    }
+
+    fn getLoopError(range: &ModuleRange, label: &Option<Label>, keyword: &str) -> ResolutionError {
+        return if let Some(label) = label {
+            ResolutionError::UnknownLabel(label.identifier.getFileRange(), label.identifier.getToken().getSourceRange().getSourceInRange().to_owned())
+        } else {
+            ResolutionError::Unexpected(range.getFileRange(), format!("{keyword} outside of loop"))
+        };
+    }
+
     fn resolve(&mut self, symbol: &Symbol, global: bool) -> Option<Statement> {
         match self.resolver.last().unwrap().to_owned().resolve(self, symbol) {
             Resolution::Ok(symbol) => return Some(symbol),
@@ -309,48 +458,131 @@ impl ResolutionHandler {
                 Some(Statement::Scope(self.resolveBlock(symbol)?))
             }
             Symbol::While(symbol) => {
-                return getResolvedExpression(self, &symbol.condition, global, Box::new(|resolutionHandler, expr| {
-                    return if expr.getExpressionType() == BOOLEAN_TYPE.to_owned() {
-                        let statement = resolutionHandler.resolve(symbol.symbol.deref(), global)?;
-                        Some(Statement::While(Box::new(WhileStatement {
-                            condition: expr,
-                            statement,
-                        })))
-                    } else {
-                        resolutionHandler.errorVec.push(ResolutionError::ExpectedType(BOOLEAN_TYPE.to_owned(), expr.getExpressionType(), format!("expected boolean conditional for while loop")));
-                        None
-                    };
-                })).flatten();
+                let condition = self.resolveCondition(&symbol.condition, global, "expected boolean conditional for while loop");
+                // body is resolved even if the condition failed, so that errors in the body are reported
+                let id = self.pushLoopResolver(symbol.label.as_ref());
+                let statement = self.resolve(symbol.symbol.deref(), global);
+                self.popResolver();
+                return Some(Statement::While(Box::new(WhileStatement {
+                    id,
+                    condition: condition?,
+                    statement: statement?,
+                })));
+            }
+            Symbol::Loop(symbol) => {
+                let id = self.pushLoopResolver(symbol.label.as_ref());
+                let statement = self.resolve(symbol.symbol.deref(), global);
+                self.popResolver();
+                return Some(Statement::Loop(Box::new(LoopStatement {
+                    id,
+                    statement: statement?,
+                })));
+            }
+            Symbol::For(symbol) => {
+                // bounds are resolved outside of the loop scope, so they cannot reference the loop variable
+                let bounds = self.resolveRange(&symbol.iterable, global);
+                self.pushScope();
+                let variableName = symbol.variable.getToken().getSourceRange().getSourceInRange();
+                let variable = self.scope.declareVariable(variableName, symbol.variable.getFileRange(), INTEGER_TYPE.to_owned(), &mut self.errorVec);
+                let id = self.pushLoopResolver(symbol.label.as_ref());
+                let statement = self.resolve(symbol.symbol.deref(), global);
+                self.popResolver();
+                self.popScope();
+                let (start, end) = bounds?;
+                let variable = variable?;
+                return Some(Statement::For(Box::new(ForStatement {
+                    id,
+                    variable: VariableDeclare {
+                        ty: variable.ty,
+                        id: variable.id,
+                        global: false,
+                    },
+                    start,
+                    end,
+                    statement: statement?,
+                })));
             }
             Symbol::IfSym(symbol) => {
-                return getResolvedExpression(self, &symbol.condition, global, Box::new(|resolutionHandler, expr| {
-                    return if expr.getExpressionType() == BOOLEAN_TYPE.to_owned() {
-                        let statement = resolutionHandler.resolve(symbol.symbol.deref(), false)?;
-                        let elseStatement = if let Some(elseSym) = &symbol.elseExpr {
-                            Some(resolutionHandler.resolve(&elseSym.symbol, false)?)
-                        } else {
+                let condition = self.resolveCondition(&symbol.condition, global, "expected boolean conditional for if statement");
+                // branches are resolved even if the condition failed, so that errors in the branches are reported
+                let statement = self.resolve(symbol.symbol.deref(), false);
+                let elseStatement = symbol.elseExpr.as_ref().map(|elseSym| self.resolve(&elseSym.symbol, false));
+                return Some(Statement::If(Box::new(IfStatement {
+                    condition: condition?,
+                    statement: statement?,
+                    elseStatement: match elseStatement {
+                        Some(elseStatement) => Some(elseStatement?),
+                        None => None,
+                    },
+                })));
+            }
+            Symbol::Match(symbol) => {
+                let value = self.resolveExpr(&symbol.value, global);
+                let valueType = value.as_ref().map(|value| value.getExpressionType());
+                if let Some(valueType) = valueType.as_ref().filter(|ty| ty.getEnumType().is_none()) {
+                    self.errorVec.push(ResolutionError::InvalidOperationType(symbol.value.getRange().getFileRange(), valueType.to_owned(), format!("cannot match on value of type")));
+                }
+                let enumType = valueType.as_ref().and_then(|ty| ty.getEnumType());
+                // cases are resolved even if the value failed, so that errors in the cases are reported
+                let mut failed = enumType.is_none();
+                let mut caseVec = Vec::new();
+                for case in &symbol.caseVec {
+                    let statement = self.resolve(&case.symbol, false);
+                    let variantName = case.variant.getToken().getSourceRange().getSourceInRange();
+                    let variant = enumType.and_then(|enumType| match enumType.getVariant(variantName) {
+                        Some(variant) if caseVec.iter().any(|case: &MatchCase| case.variant == variant) => {
+                            self.errorVec.push(ResolutionError::InvalidOperation(case.variant.getFileRange(), format!("variant '{variantName}' is matched more than once")));
+                            None
+                        }
+                        Some(variant) => Some(variant),
+                        None => {
+                            self.errorVec.push(ResolutionError::UnknownVariable(case.variant.getFileRange(), format!("{}.{variantName}", enumType.getTypeName())));
                             None
-                        };
-                        Some(Statement::If(Box::new(IfStatement {
-                            condition: expr,
+                        }
+                    });
+                    match (variant, statement) {
+                        (Some(variant), Some(statement)) => caseVec.push(MatchCase {
+                            variant,
                             statement,
-                            elseStatement,
-                        })))
-                    } else {
-                        resolutionHandler.errorVec.push(ResolutionError::ExpectedType(BOOLEAN_TYPE.to_owned(), expr.getExpressionType(), format!("expected boolean conditional for if statement")));
-                        None
-                    };
-                })).flatten();
+                        }),
+                        _ => failed = true,
+                    }
+                }
+                let elseStatement = match &symbol.elseSym {
+                    Some(elseSym) => match self.resolve(&elseSym.symbol, false) {
+                        Some(statement) => Some(statement),
+                        None => return None,
+                    },
+                    None => None,
+                };
+                if failed {
+                    return None;
+                }
+                let enumType = enumType.unwrap();
+                let missingVec = enumType.getVariants().iter().enumerate().filter(|(variant, _)| caseVec.iter().all(|case| case.variant != *variant)).map(|(_, name)| name.to_owned()).collect::<Vec<_>>();
+                if let Some(elseSym) = &symbol.elseSym {
+                    if missingVec.is_empty() {
+                        self.warningVec.push(ResolutionWarning::UnreachableCode(elseSym.symbol.getSymbolType().getRange().getFileRange()));
+                    }
+                } else if !missingVec.is_empty() {
+                    self.errorVec.push(ResolutionError::NonExhaustiveMatch(symbol.value.getRange().getFileRange(), valueType.unwrap(), missingVec));
+                    return None;
+                }
+                return Some(Statement::Match(Box::new(MatchStatement {
+                    value: value?,
+                    caseVec,
+                    elseStatement,
+                })));
             }
             Symbol::PrintSym(symbol) => {
                 return getResolvedExpression(self, &symbol.expr, false, Box::new(|resolutionHandler, expr| {
                     let ty = expr.getExpressionType();
-                    return if ty == INTEGER_TYPE || ty == FLOAT_TYPE || ty == STRING_TYPE {
+                    return if ty == INTEGER_TYPE || ty == FLOAT_TYPE || ty == STRING_TYPE || ty.getEnumType().is_some() {
                         Some(Statement::Print(PrintStatement {
                             value: expr,
                         }))
                     } else {
-                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(ty, format!("Cannot call print on type")));
+                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(symbol.expr.getRange().getFileRange(), ty, format!("Cannot call print on type")));
                         None
                     };
                 })).flatten();
@@ -359,27 +591,32 @@ impl ResolutionHandler {
                 Some(Statement::Expr(self.resolveExpr(expr, global)?))
             }
             Symbol::ClassDefinition(symbol) => {
-                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getStartPos(), format!("unexpected class definition {:?}", self.resolver.last().unwrap())));
+                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getFileRange(), format!("unexpected class definition {:?}", self.resolver.last().unwrap())));
+                return None;
+            }
+            Symbol::EnumDefinition(symbol) => {
+                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getFileRange(), "unexpected enum definition".to_owned()));
                 return None;
             }
             Symbol::Return(symbol) => {
-                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getStartPos(), "unexpected return statement".to_owned()));
+                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getFileRange(), "unexpected return statement".to_owned()));
                 return None;
             }
             Symbol::FunctionDefinition(symbol) => {
-                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getStartPos(), "unexpected function definition".to_owned()));
+                self.errorVec.push(ResolutionError::Unexpected(symbol.getRange().getFileRange(), "unexpected function definition".to_owned()));
                 return None;
             }
+            // break and continue within a loop are resolved by the loop resolver
             Symbol::Break(symbol) => {
-                self.errorVec.push(ResolutionError::Unsupported(symbol.range.getStartPos(), "break".to_owned()));
+                self.errorVec.push(Self::getLoopError(&symbol.range, &symbol.label, "break"));
                 return None;
             }
             Symbol::Continue(symbol) => {
-                self.errorVec.push(ResolutionError::Unsupported(symbol.range.getStartPos(), "continue".to_owned()));
+                self.errorVec.push(Self::getLoopError(&symbol.range, &symbol.label, "continue"));
                 return None;
             }
             Symbol::ImportSym(symbol) => {
-                self.errorVec.push(ResolutionError::Unsupported(symbol.range.getStartPos(), "import".to_owned()));
+                self.errorVec.push(ResolutionError::Unsupported(symbol.range.getFileRange(), "import".to_owned()));
                 return None;
             }
         };
@@ -402,43 +639,43 @@ impl Scope {
     }
 
     fn getVariable(&self, name: &str) -> Option<ResolvedVariable> {
-        return self.variableMap.get(name).map(|v| v.to_owned()).or_else(|| if let Some(parent) = &self.parent {
+        return self.variableMap.get(name).map(|(v, _)| v.to_owned()).or_else(|| if let Some(parent) = &self.parent {
             parent.getVariable(name)
         } else {
             None
         });
     }
 
-    fn getVariableOrError(&self, variableName: &str, errorVec: &mut Vec<ResolutionError>) -> Option<ResolvedVariable> {
+    fn getVariableOrError(&self, variableName: &str, range: FileRange, errorVec: &mut Vec<ResolutionError>) -> Option<ResolvedVariable> {
         return if let Some(variable) = self.getVariable(variableName) {
             Some(variable)
         } else {
-            errorVec.push(ResolutionError::UnknownVariable(format!("unknown variable '{}'", variableName)));
+            errorVec.push(ResolutionError::UnknownVariable(range, variableName.to_owned()));
             None
         };
     }
 
-    fn declareVariable(&mut self, name: &str, ty: Type, errorVec: &mut Vec<ResolutionError>) -> Option<ResolvedVariable> {
+    fn declareVariable(&mut self, name: &str, range: FileRange, ty: Type, errorVec: &mut Vec<ResolutionError>) -> Option<ResolvedVariable> {
         static NEXT_VARIABLE_ID: AtomicUsize = AtomicUsize::new(0);
 
         return match self.variableMap.entry(name.to_owned()) {
-            Entry::Occupied(_) => {
-                errorVec.push(ResolutionError::ConflictingVariable(name.to_owned(), format!("found multiple variables in scope with same variable name")));
+            Entry::Occupied(v) => {
+                errorVec.push(ResolutionError::ConflictingVariable(range, name.to_owned(), format!("found multiple variables in scope with same variable name"), v.get().1.to_owned()));
                 None
             }
             Entry::Vacant(v) => {
-                Some(v.insert(ResolvedVariable {
+                Some(v.insert((ResolvedVariable {
                     ty,
                     id: NEXT_VARIABLE_ID.fetch_add(1, Ordering::Relaxed),
-                }).to_owned())
+                }, range)).0.to_owned())
             }
         };
     }
 }
 
-fn getResolvedType<R>(resolutionHandler: &mut ResolutionHandler, modulePos: &ModulePos, callback: impl FnOnce(&mut ResolutionHandler, Type) -> R) -> Option<R> {
-    let typeName = modulePos.getToken().getSourceRange().getSourceInRange();
-    return match resolutionHandler.exportTable.getExportedType(typeName) {
+fn getResolvedType<R>(resolutionHandler: &mut ResolutionHandler, range: &ModuleRange, callback: impl FnOnce(&mut ResolutionHandler, Type) -> R) -> Option<R> {
+    let fileRange = range.getFileRange();
+    return match resolutionHandler.exportTable.getExportedType(fileRange.getSourceInRange(), &fileRange) {
         Ok(ty) => Some(callback(resolutionHandler, ty)),
         Err(err) => {
             resolutionHandler.errorVec.push(err);
@@ -447,6 +684,16 @@ fn getResolvedType<R>(resolutionHandler: &mut ResolutionHandler, modulePos: &Mod
     };
 }
 
+// enum named by the left operand of a dot operator (e.g. Color.Red), variables take precedence over types
+fn getReferencedEnumType(resolutionHandler: &ResolutionHandler, expr: &Expr) -> Option<Type> {
+    let Expr::Variable(variable) = expr else { return None };
+    let name = variable.range.getSource();
+    if resolutionHandler.scope.getVariable(&name).is_some() {
+        return None;
+    }
+    return resolutionHandler.exportTable.getExportedType(&name, &variable.range.getFileRange()).ok().filter(|ty| ty.getEnumType().is_some());
+}
+
 fn getResolvedFunctionCall(resolutionHandler: &mut ResolutionHandler, function: Function, functionCall: &FunctionCallExpr, selfValue: Option<ResolvedExpr>) -> Option<FunctionCall> {
     let mut argVec = Vec::new();
     if let Some(selfValue) = selfValue {
@@ -460,7 +707,7 @@ fn getResolvedFunctionCall(resolutionHandler: &mut ResolutionHandler, function:
                 if expression.getExpressionType() == function.parameters[index].ty {
                     argVec.push(expression);
                 } else {
-                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(function.parameters[index].ty.to_owned(), expression.getExpressionType(), format!("parameter type incorrect in function call")));
+                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(functionCall.argVec[index].getRange().getFileRange(), function.parameters[index].ty.to_owned(), expression.getExpressionType(), format!("parameter type incorrect in function call")));
                 }
             }));
         }
@@ -474,7 +721,7 @@ fn getResolvedFunctionCall(resolutionHandler: &mut ResolutionHandler, function:
             None
         }
     } else {
-        resolutionHandler.errorVec.push(ResolutionError::ParameterMismatch(function.to_owned(), format!("parameter mismatch: expected {} args, found {}", function.parameters.len(), functionCall.argVec.len())));
+        resolutionHandler.errorVec.push(ResolutionError::ParameterMismatch(functionCall.range.getFileRange(), function.to_owned(), format!("parameter mismatch: expected {} args, found {}", function.parameters.len(), functionCall.argVec.len())));
         None
     };
 }
@@ -483,7 +730,7 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
     let resolved = match expr {
         // Expr::ConstructorCall(expr) => {
         //     if !expr.argVec.is_empty() {
-        //         resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getStartPos(), format!("constructors do not support arguments")));
+        //         resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getFileRange(), format!("constructors do not support arguments")));
         //         return None;
         //     }
         //     getResolvedType(resolutionHandler, &expr.typeName, |_, ty| {
@@ -493,7 +740,7 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
         //     })?
         // }
         Expr::FunctionCall(expr) => {
-            match resolutionHandler.exportTable.getExportedFunction(expr.functionName.getToken().getSourceRange().getSourceInRange()) {
+            match resolutionHandler.exportTable.getExportedFunction(expr.functionName.getToken().getSourceRange().getSourceInRange(), &expr.functionName.getFileRange()) {
                 Ok(function) => {
                     ResolvedExpr::FunctionCall(Box::new(getResolvedFunctionCall(resolutionHandler, function, expr, None)?))
                 }
@@ -503,8 +750,94 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
                 }
             }
         }
+        Expr::Operator(expr) if matches!(expr.operator, Operator::AssignEq) && matches!(&expr.operands[0], Expr::VariableDeclaration(_)) => {
+            // declaration with initializer, the value is resolved before the variable is declared (so that the type may be inferred from the value)
+            let Expr::VariableDeclaration(declaration) = &expr.operands[0] else { unreachable!() };
+            let variableName = declaration.variableName.getToken().getSourceRange().getSourceInRange();
+            let value = getResolvedExpression(resolutionHandler, &expr.operands[1], global, Box::new(|_, resolved| resolved));
+            let valueType = value.as_ref().map(|value| value.getExpressionType());
+            let ty = if let Some(explicitType) = &declaration.explicitType {
+                let ty = getResolvedType(resolutionHandler, explicitType, |_, ty| ty);
+                if let (Some(ty), Some(valueType)) = (&ty, &valueType) {
+                    if ty != valueType {
+                        resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.range.getFileRange(), ty.to_owned(), valueType.to_owned(), format!("mismatched types for assignment")));
+                    }
+                }
+                ty
+            } else {
+                if valueType == Some(VOID_TYPE.to_owned()) {
+                    resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[1].getRange().getFileRange(), VOID_TYPE.to_owned(), format!("cannot infer type of variable '{variableName}' from value of type")));
+                }
+                valueType.to_owned().filter(|ty| *ty != VOID_TYPE.to_owned())
+            };
+            // variable is still declared if the value failed to resolve, so that uses of the variable are not reported as unknown
+            let variable = resolutionHandler.scope.declareVariable(variableName, declaration.variableName.getFileRange(), ty.to_owned().unwrap_or(ERROR_TYPE.to_owned()), &mut resolutionHandler.errorVec)?;
+            if variable.ty == ERROR_TYPE || valueType != Some(variable.ty.to_owned()) {
+                return None;
+            }
+            ResolvedExpr::VariableInitialize(Box::new(VariableInitialize {
+                variable: VariableDeclare {
+                    ty: variable.ty,
+                    id: variable.id,
+                    global,
+                },
+                value: value?,
+            }))
+        }
+        Expr::Operator(expr) if matches!(expr.operator, Operator::AssignEq) && matches!(&expr.operands[0], Expr::TupleDeclaration(_)) => {
+            // destructuring declaration, each variable takes the type of the tuple element at the same position
+            let Expr::TupleDeclaration(declaration) = &expr.operands[0] else { unreachable!() };
+            let value = getResolvedExpression(resolutionHandler, &expr.operands[1], global, Box::new(|_, resolved| resolved));
+            let elementTypes = match value.as_ref().map(|value| value.getExpressionType()) {
+                Some(valueType) => match valueType.getTupleType() {
+                    Some(tupleType) if tupleType.elementTypes.len() == declaration.variableNames.len() => Some(tupleType.elementTypes.to_owned()),
+                    Some(tupleType) => {
+                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.range.getFileRange(), valueType.to_owned(), format!("expected {} variables to destructure value of type", tupleType.elementTypes.len())));
+                        None
+                    }
+                    None => {
+                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[1].getRange().getFileRange(), valueType.to_owned(), format!("cannot destructure value of type")));
+                        None
+                    }
+                },
+                None => None,
+            };
+            // variables are still declared if the value failed to resolve, so that uses of the variables are not reported as unknown
+            let mut variableVec = Vec::new();
+            for (index, variableName) in declaration.variableNames.iter().enumerate() {
+                let ty = elementTypes.as_ref().map(|elementTypes| elementTypes[index].to_owned()).unwrap_or(ERROR_TYPE.to_owned());
+                if let Some(variable) = resolutionHandler.scope.declareVariable(variableName.getToken().getSourceRange().getSourceInRange(), variableName.getFileRange(), ty, &mut resolutionHandler.errorVec) {
+                    variableVec.push(VariableDeclare {
+                        ty: variable.ty,
+                        id: variable.id,
+                        global,
+                    });
+                }
+            }
+            if elementTypes.is_none() || variableVec.len() != declaration.variableNames.len() {
+                return None;
+            }
+            ResolvedExpr::TupleInitialize(Box::new(TupleInitialize {
+                variableVec,
+                value: value?,
+            }))
+        }
         Expr::Operator(expr) => {
-            if let Operator::Dot = expr.operator {
+            if let (Operator::Dot, Some(enumType)) = (expr.operator, getReferencedEnumType(resolutionHandler, &expr.operands[0])) {
+                let variantName = expr.operands[1].getRange().getSource();
+                let variant = match &expr.operands[1] {
+                    Expr::Variable(_) => enumType.getEnumType().unwrap().getVariant(&variantName),
+                    _ => None,
+                };
+                let Some(variant) = variant else {
+                    resolutionHandler.errorVec.push(ResolutionError::UnknownVariable(expr.operands[1].getRange().getFileRange(), format!("{}.{variantName}", enumType.getTypeName())));
+                    return None;
+                };
+                ResolvedExpr::LiteralEnum(LiteralEnum {
+                    ty: enumType,
+                    variant,
+                })
+            } else if let Operator::Dot = expr.operator {
                 let structure = getResolvedExpression(resolutionHandler, &expr.operands[0], global, Box::new(|_, resolved| resolved));
                 debug_assert!(structure.is_some() || !resolutionHandler.errorVec.is_empty(), "failed to resolve {:?} but no error provided", &expr.operands[0]);
                 let structure = structure?;
@@ -519,28 +852,98 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
                                 ResolvedExpr::FunctionCall(Box::new(functionCall))
                             }
                             None => {
-                                resolutionHandler.errorVec.push(ResolutionError::UnknownFunction(format!("unable to find method '{functionName}' of class '{}'", structureType.getTypeName())));
+                                resolutionHandler.errorVec.push(ResolutionError::UnknownFunction(functionCall.functionName.getFileRange(), format!("{}.{functionName}", structureType.getTypeName())));
                                 return None;
                             }
                         }
                     }
                     Expr::Variable(variable) => {
                         let variableName = variable.getRange().getSource();
-                        if let Some(property) = structureType.getPropertyMap().get(&variableName) {
+                        if let Some(arrayType) = structureType.getArrayType().filter(|_| variableName == "length") {
+                            // array length is known at compile time, but values other than variables may have side effects
+                            if let ResolvedExpr::Variable(_) = structure {
+                                ResolvedExpr::LiteralInteger(arrayType.length as _)
+                            } else {
+                                ResolvedExpr::ArrayLength(Box::new(ArrayLength {
+                                    length: arrayType.length,
+                                    value: structure,
+                                }))
+                            }
+                        } else if let Some(property) = structureType.getPropertyMap().get(&variableName) {
                             ResolvedExpr::Property(Box::new(ResolvedProperty {
                                 value: structure,
                                 property: property.to_owned(),
                             }))
                         } else {
-                            resolutionHandler.errorVec.push(ResolutionError::UnknownVariable(format!("unable to find field '{variableName}' of class {}", structureType.getTypeName())));
+                            resolutionHandler.errorVec.push(ResolutionError::UnknownVariable(variable.range.getFileRange(), format!("{}.{variableName}", structureType.getTypeName())));
                             return None;
                         }
                     }
+                    Expr::LiteralInteger(_) | Expr::LiteralFloat(_) => {
+                        // tuple element (t.1.0 is lexed as t . 1.0, so each position in the number is accessed in turn)
+                        let mut value = structure;
+                        for elementName in expr.operands[1].getRange().getSource().split('.') {
+                            let valueType = value.getExpressionType();
+                            let Some(property) = valueType.getTupleType().and_then(|_| valueType.getPropertyMap().get(elementName)) else {
+                                resolutionHandler.errorVec.push(ResolutionError::UnknownVariable(expr.operands[1].getRange().getFileRange(), format!("{}.{elementName}", valueType.getTypeName())));
+                                return None;
+                            };
+                            value = ResolvedExpr::Property(Box::new(ResolvedProperty {
+                                value,
+                                property: property.to_owned(),
+                            }));
+                        }
+                        value
+                    }
                     _ => {
-                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(format!("dot operator can only be used to access a variable or function, found {:?}", expr.operands[1])));
+                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[1].getRange().getFileRange(), format!("dot operator can only be used to access a variable or function, found {:?}", expr.operands[1])));
                         return None;
                     }
                 }
+            } else if let Operator::Index = expr.operator {
+                let value = getResolvedExpression(resolutionHandler, &expr.operands[0], global, Box::new(|_, resolved| resolved));
+                // index remains wrapped in the square parenthesis it was written in
+                let Expr::LiteralArray(indexParenthesis) = &expr.operands[1] else { unreachable!() };
+                let index = getResolvedExpression(resolutionHandler, &indexParenthesis.exprVec[0], global, Box::new(|_, resolved| resolved));
+                let (value, index) = (value?, index?);
+                let valueType = value.getExpressionType();
+                let ty = if let Some(arrayType) = valueType.getArrayType() {
+                    arrayType.elementType.to_owned()
+                } else if valueType == STRING_TYPE.to_owned() {
+                    CHARACTER_TYPE.to_owned()
+                } else {
+                    resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), valueType, format!("cannot index value of type")));
+                    return None;
+                };
+                if index.getExpressionType() != INTEGER_TYPE.to_owned() {
+                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(indexParenthesis.exprVec[0].getRange().getFileRange(), INTEGER_TYPE.to_owned(), index.getExpressionType(), format!("index must be an integer")));
+                    return None;
+                }
+                ResolvedExpr::Index(Box::new(ResolvedIndex {
+                    value,
+                    index,
+                    ty,
+                    range: expr.range.getFileRange(),
+                }))
+            } else if let Operator::Cast = expr.operator {
+                let value = getResolvedExpression(resolutionHandler, &expr.operands[0], global, Box::new(|_, resolved| resolved));
+                let ty = match &expr.operands[1] {
+                    Expr::Variable(typeName) => getResolvedType(resolutionHandler, &typeName.range, |_, ty| ty),
+                    _ => {
+                        resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[1].getRange().getFileRange(), format!("expected type name for cast")));
+                        None
+                    }
+                };
+                let (value, ty) = (value?, ty?);
+                let valueType = value.getExpressionType();
+                if valueType != ty && !valueType.getExplicitConversions().contains(&ty) {
+                    resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.range.getFileRange(), ty, format!("cannot cast '{}' to", valueType.getTypeName())));
+                    return None;
+                }
+                ResolvedExpr::Cast(Box::new(ResolvedCast {
+                    value,
+                    ty,
+                }))
             } else {
                 let mut exprVec = Vec::new();
 
@@ -556,26 +959,37 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
                         }
 
                         if exprVec[0].getExpressionType() != exprVec[1].getExpressionType() {
-                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
+                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.range.getFileRange(), exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
                             return None;
                         }
 
-                        if !isPrimitiveType(exprVec[0].getExpressionType()) {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-primitive type", expr.operator)));
+                        // enums can only be compared for equality
+                        let isEnumEquality = matches!(expr.operator, Operator::CompareEq | Operator::CompareNotEq) && exprVec[0].getExpressionType().getEnumType().is_some();
+                        if !isPrimitiveType(exprVec[0].getExpressionType()) && !isEnumEquality {
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-primitive type", expr.operator)));
                             return None;
                         }
 
                         BOOLEAN_TYPE.to_owned()
                     }
-                    Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div => {
+                    Operator::Plus | Operator::Minus | Operator::Mult | Operator::Div | Operator::Mod => {
                         // arithmetic type
                         if exprVec[0].getExpressionType() != exprVec[1].getExpressionType() {
-                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
+                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.range.getFileRange(), exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
                             return None;
                         }
 
                         if !exprVec[0].getExpressionType().isArithmeticType() {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
+                            return None;
+                        }
+
+                        exprVec[0].getExpressionType()
+                    }
+                    Operator::Negate => {
+                        // arithmetic type
+                        if !exprVec[0].getExpressionType().isArithmeticType() {
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
                             return None;
                         }
 
@@ -584,62 +998,67 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
                     Operator::ModAssign | Operator::DivAssign | Operator::MultAssign | Operator::MinusAssign | Operator::PlusAssign => {
                         // arithmetic type
                         if exprVec[0].getExpressionType() != exprVec[1].getExpressionType() {
-                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
+                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.range.getFileRange(), exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for operation expression")));
                             return None;
                         }
 
                         if !exprVec[0].getExpressionType().isArithmeticType() {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
                             return None;
                         }
 
                         if !exprVec[0].getResolvedExprType().isAssignable() {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(format!("value is not assignable")));
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[0].getRange().getFileRange(), format!("value is not assignable")));
                             return None;
                         }
 
                         if matches!(exprVec[0], ResolvedExpr::VariableDeclaration(_)) {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(format!("cannot apply operator {:?} to variable declaration", expr.operator)));
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[0].getRange().getFileRange(), format!("cannot apply operator {:?} to variable declaration", expr.operator)));
                             return None;
                         }
 
                         exprVec[0].getExpressionType()
                     }
-                    Operator::Increment | Operator::Decrement | Operator::Mod => {
-                        // integer
-                        let exprType = exprVec[0].getExpressionType();
-                        if exprType != INTEGER_TYPE.to_owned() {
-                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(INTEGER_TYPE.to_owned(), exprType, format!("expected integer for operator {:?}", expr.operator)));
+                    Operator::Increment | Operator::Decrement | Operator::PrefixIncrement | Operator::PrefixDecrement => {
+                        // assignable arithmetic type
+                        if !exprVec[0].getExpressionType().isArithmeticType() {
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.operands[0].getRange().getFileRange(), exprVec[0].getExpressionType(), format!("cannot apply {:?} operator to non-arithmetic type", expr.operator)));
+                            return None;
+                        }
+
+                        if !exprVec[0].getResolvedExprType().isAssignable() || matches!(exprVec[0], ResolvedExpr::VariableDeclaration(_)) {
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[0].getRange().getFileRange(), format!("value is not assignable")));
                             return None;
                         }
-                        exprType
+
+                        exprVec[0].getExpressionType()
                     }
                     Operator::And | Operator::Or | Operator::Not => {
                         // bool
-                        for operand in &exprVec {
+                        for (index, operand) in exprVec.iter().enumerate() {
                             if operand.getExpressionType() != BOOLEAN_TYPE.to_owned() {
-                                resolutionHandler.errorVec.push(ResolutionError::ExpectedType(BOOLEAN_TYPE.to_owned(), operand.getExpressionType(), format!("operator {:?} must be applied to a boolean expression", expr.operator)));
+                                resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.operands[index].getRange().getFileRange(), BOOLEAN_TYPE.to_owned(), operand.getExpressionType(), format!("operator {:?} must be applied to a boolean expression", expr.operator)));
                                 return None;
                             }
                         }
                         BOOLEAN_TYPE.to_owned()
                     }
-                    Operator::Dot => unreachable!(),
+                    Operator::Dot | Operator::Index | Operator::Cast => unreachable!(),
                     Operator::AssignEq => {
                         // any type
                         if exprVec[0].getExpressionType() != exprVec[1].getExpressionType() {
-                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for assignment")));
+                            resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.range.getFileRange(), exprVec[0].getExpressionType(), exprVec[1].getExpressionType(), format!("mismatched types for assignment")));
                             return None;
                         }
 
                         if !exprVec[0].getResolvedExprType().isAssignable() {
-                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(format!("value is not assignable")));
+                            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.operands[0].getRange().getFileRange(), format!("value is not assignable")));
                             return None;
                         }
                         exprVec[0].getExpressionType()
                     }
-                    Operator::Cast | Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation => {
-                        resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getStartPos(), format!("unsupported operator {:?}", expr.operator)));
+                    Operator::Range | Operator::Ellipsis | Operator::Colon | Operator::ErrorPropagation => {
+                        resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getFileRange(), format!("operator {:?}", expr.operator)));
                         return None;
                     }
                 };
@@ -652,23 +1071,30 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
             }
         }
         Expr::VariableDeclaration(expr) => {
-            if let Some(explicitType) = &expr.explicitType {
-                getResolvedType(resolutionHandler, explicitType, |resolutionHandler, ty| {
-                    let variable = resolutionHandler.scope.declareVariable(expr.variableName.getToken().getSourceRange().getSourceInRange(), ty, &mut resolutionHandler.errorVec)?;
-                    Some(ResolvedExpr::VariableDeclaration(VariableDeclare {
-                        ty: variable.ty,
-                        id: variable.id,
-                        global,
-                    }))
-                }).flatten()?
+            let variableName = expr.variableName.getToken().getSourceRange().getSourceInRange();
+            let ty = if let Some(explicitType) = &expr.explicitType {
+                getResolvedType(resolutionHandler, explicitType, |_, ty| ty)
             } else {
-                resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getStartPos(), format!("type inference not supported (for variable '{}')", expr.variableName.getToken().getSourceRange().getSourceInRange())));
-                return None;
-            }
+                resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.range.getFileRange(), format!("cannot infer type of variable '{variableName}' without a value")));
+                None
+            };
+            // variable is still declared if the type failed to resolve, so that uses of the variable are not reported as unknown
+            let variable = resolutionHandler.scope.declareVariable(variableName, expr.variableName.getFileRange(), ty.to_owned().unwrap_or(ERROR_TYPE.to_owned()), &mut resolutionHandler.errorVec)?;
+            ty?;
+            ResolvedExpr::VariableDeclaration(VariableDeclare {
+                ty: variable.ty,
+                id: variable.id,
+                global,
+            })
         }
         Expr::Variable(expr) => {
             let variableName = &expr.range.getSource();
-            ResolvedExpr::Variable(resolutionHandler.scope.getVariableOrError(variableName, &mut resolutionHandler.errorVec)?)
+            let variable = resolutionHandler.scope.getVariableOrError(variableName, expr.range.getFileRange(), &mut resolutionHandler.errorVec)?;
+            if variable.ty == ERROR_TYPE {
+                // error has already been reported for the declaration
+                return None;
+            }
+            ResolvedExpr::Variable(variable)
         }
         Expr::ReadExpr(_) => {
             ResolvedExpr::Read(Default::default())
@@ -689,14 +1115,237 @@ fn getResolvedExpression<'a, R>(resolutionHandler: &mut ResolutionHandler, expr:
             ResolvedExpr::LiteralString(expr.fileRange.getSourceInRange().to_owned())
         }
         Expr::LiteralArray(expr) => {
-            resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getStartPos(), "unsupported array literal".to_owned()));
-            return None;
+            // every element is resolved before returning, so that all errors are reported
+            let valueVec = expr.exprVec.iter().map(|value| getResolvedExpression(resolutionHandler, value, global, Box::new(|_, resolved| resolved))).collect::<Vec<_>>();
+            let valueVec = valueVec.into_iter().collect::<Option<Vec<_>>>()?;
+            let Some(first) = valueVec.first() else {
+                resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.range.getFileRange(), format!("cannot infer type of empty array literal")));
+                return None;
+            };
+            let elementType = first.getExpressionType();
+            if elementType == VOID_TYPE.to_owned() {
+                resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.exprVec[0].getRange().getFileRange(), elementType, format!("array elements cannot be of type")));
+                return None;
+            }
+            for (index, value) in valueVec.iter().enumerate() {
+                if value.getExpressionType() != elementType {
+                    resolutionHandler.errorVec.push(ResolutionError::ExpectedType(expr.exprVec[index].getRange().getFileRange(), elementType, value.getExpressionType(), format!("mismatched types in array literal")));
+                    return None;
+                }
+            }
+            ResolvedExpr::LiteralArray(Box::new(LiteralArray {
+                ty: ArrayType::new(elementType, valueVec.len()),
+                valueVec,
+            }))
+        }
+        Expr::LiteralTuple(expr) if expr.exprVec.len() == 1 => {
+            // parenthesized expression
+            return getResolvedExpression(resolutionHandler, &expr.exprVec[0], global, callback);
         }
         Expr::LiteralTuple(expr) => {
-            resolutionHandler.errorVec.push(ResolutionError::Unsupported(expr.range.getStartPos(), "unsupported tuple literal".to_owned()));
+            // every element is resolved before returning, so that all errors are reported
+            let valueVec = expr.exprVec.iter().map(|value| getResolvedExpression(resolutionHandler, value, global, Box::new(|_, resolved| resolved))).collect::<Vec<_>>();
+            let valueVec = valueVec.into_iter().collect::<Option<Vec<_>>>()?;
+            for (index, value) in valueVec.iter().enumerate() {
+                if value.getExpressionType() == VOID_TYPE.to_owned() {
+                    resolutionHandler.errorVec.push(ResolutionError::InvalidOperationType(expr.exprVec[index].getRange().getFileRange(), VOID_TYPE.to_owned(), format!("tuple elements cannot be of type")));
+                    return None;
+                }
+            }
+            ResolvedExpr::LiteralTuple(Box::new(LiteralTuple {
+                ty: TupleType::new(valueVec.iter().map(|value| value.getExpressionType()).collect()),
+                valueVec,
+            }))
+        }
+        Expr::TupleDeclaration(expr) => {
+            resolutionHandler.errorVec.push(ResolutionError::InvalidOperation(expr.range.getFileRange(), format!("cannot infer types of destructured variables without a value")));
+            // variables are still declared, so that uses of the variables are not reported as unknown
+            for variableName in &expr.variableNames {
+                resolutionHandler.scope.declareVariable(variableName.getToken().getSourceRange().getSourceInRange(), variableName.getFileRange(), ERROR_TYPE.to_owned(), &mut resolutionHandler.errorVec);
+            }
             return None;
         }
     };
 
     return Some(callback(resolutionHandler, resolved));
 }
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use crate::ast::AbstractSyntaxTree;
+    use crate::module::{Module, SourceFile};
+    use crate::resolver::exporttable::GlobalExportTable;
+    use crate::resolver::resolutionerror::ResolutionError;
+    use crate::resolver::Resolver;
+
+    // errors from resolving a single source file
+    fn getResolutionErrors(source: &str) -> Vec<ResolutionError> {
+        let module = Module::new(SourceFile::fromSource(PathBuf::from("test.txt"), source.to_owned())).unwrap_or_else(|error| panic!("{}", error.getDiagnostic().render()));
+        let (ast, astErrorVec) = AbstractSyntaxTree::new(module);
+        assert!(astErrorVec.is_empty(), "{astErrorVec:?}");
+
+        let exportTable = GlobalExportTable::new();
+        let resolver = match Resolver::new(ast, exportTable.to_owned()) {
+            Ok(resolver) => resolver,
+            Err(errorVec) => return errorVec,
+        };
+        // export table is completed while resolving (see Compiler::getJobResult)
+        let exportThread = std::thread::spawn(move || exportTable.getExportErrorsBlocking());
+        let (result, _) = resolver.getResolvedAST();
+        if let Err(errorVec) = exportThread.join().unwrap() {
+            return errorVec;
+        }
+        return result.err().unwrap_or_default();
+    }
+
+    fn getErrorMessages(source: &str) -> Vec<String> {
+        return getResolutionErrors(source).iter().map(|error| error.getErrorMessage()).collect();
+    }
+
+    // error kind, source in error range and position of each error
+    fn getErrorRanges(source: &str) -> Vec<(&'static str, String, String)> {
+        return getResolutionErrors(source).iter().map(|error| (error.into(), error.getFileRange().getSourceInRange().to_owned(), error.getFileRange().to_string())).collect();
+    }
+
+    #[test]
+    fn testErrorRange() {
+        let function = "int add(int a, int b) {\n    return a + b\n}\n";
+        assert_eq!(vec![("UnknownVariable", format!("y"), format!("test.txt:2:7"))], getErrorRanges("int x = 1\nprint(y)\n"));
+        assert_eq!(vec![("UnknownType", format!("foo"), format!("test.txt:1:1"))], getErrorRanges("foo value = 1\n"));
+        assert_eq!(vec![("ExpectedType", format!("bool flag = 5"), format!("test.txt:1:1"))], getErrorRanges("bool flag = 5\n"));
+        assert_eq!(vec![("ParameterMismatch", format!("add(1)"), format!("test.txt:4:1"))], getErrorRanges(&format!("{function}add(1)\n")));
+        assert_eq!(vec![("MissingReturn", format!("missing"), format!("test.txt:1:5"))], getErrorRanges("int missing() {\n    int x = 1\n}\n"));
+    }
+
+    #[test]
+    fn testMultipleErrors() {
+        let errorVec = getErrorRanges("\
+int first() {
+    return missing
+}
+int second() {
+    return 1 + true
+}
+int value = unknown + 1
+int doubled = value * 2
+print(doubled)
+print(other)
+");
+        // resolution continues after errors in functions and statements
+        // values which failed to resolve (value, doubled) do not cause further errors
+        assert_eq!(vec![
+            ("UnknownVariable", format!("missing"), format!("test.txt:2:12")),
+            ("ExpectedType", format!("1 + true"), format!("test.txt:5:12")),
+            ("UnknownVariable", format!("unknown"), format!("test.txt:7:13")),
+            ("UnknownVariable", format!("other"), format!("test.txt:10:7")),
+        ], errorVec);
+    }
+
+    #[test]
+    fn testErrorJson() {
+        let errorVec = getResolutionErrors("int x = 1\nprint(y)\n");
+        assert_eq!(1, errorVec.len());
+        assert_eq!(
+            "{\"severity\":\"error\",\"code\":\"UnknownVariable\",\"message\":\"unknown variable 'y'\",\"span\":{\"file\":\"test.txt\",\"byteStart\":16,\"byteEnd\":17,\"line\":2,\"column\":7,\"endLine\":2,\"endColumn\":8},\"notes\":[]}",
+            errorVec[0].getDiagnostic().toJson()
+        );
+    }
+
+    #[test]
+    fn testConflictNotes() {
+        let getNotes = |source: &str| getResolutionErrors(source).iter().flat_map(|error| error.getDiagnostic().notes).map(|note| (note.message, note.range.unwrap().to_string())).collect::<Vec<_>>();
+        assert_eq!(vec![(format!("variable 'x' previously declared here"), format!("test.txt:2:9"))], getNotes("if true {\n    int x = 1\n    float x = 2.0\n}\n"));
+        assert_eq!(vec![(format!("function 'f' previously defined here"), format!("test.txt:1:6"))], getNotes("void f() {\n}\nint f() {\n    return 1\n}\n"));
+    }
+
+    #[test]
+    fn testLoopControlErrors() {
+        assert_eq!(vec![("Unexpected", format!("break"), format!("test.txt:1:1"))], getErrorRanges("break\n"));
+        assert_eq!(vec![("Unexpected", format!("continue"), format!("test.txt:2:5"))], getErrorRanges("if true {\n    continue\n}\n"));
+        assert_eq!(vec![("UnknownLabel", format!("inner"), format!("test.txt:2:11"))], getErrorRanges("outer: while true {\n    break inner\n}\n"));
+    }
+
+    #[test]
+    fn testForRangeErrors() {
+        assert_eq!(vec!["expected integer bound for range: expected 'int', found 'float'"], getErrorMessages("for i in 0..1.5 {\n}\n"));
+        assert_eq!(vec![("Unsupported", format!("x"), format!("test.txt:2:10"))], getErrorRanges("int x = 3\nfor j in x {\n}\n"));
+        // loop variable is scoped to the loop
+        assert_eq!(vec![("UnknownVariable", format!("i"), format!("test.txt:3:7"))], getErrorRanges("for i in 0..2 {\n}\nprint(i)\n"));
+    }
+
+    #[test]
+    fn testLoopReturn() {
+        assert!(getResolutionErrors("int spin() {\n    loop {\n        return 1\n    }\n}\n").is_empty());
+        // the function can exit the loop without returning a value
+        assert_eq!(vec![("MissingReturn", format!("exit"), format!("test.txt:1:5"))], getErrorRanges("int exit() {\n    loop {\n        break\n    }\n}\n"));
+    }
+
+    #[test]
+    fn testLetVoid() {
+        let source = "void nothing() {\n}\nlet v = nothing()\nprint(v)\n";
+        // uses of the variable are not reported
+        assert_eq!(vec![("InvalidOperationType", format!("nothing()"), format!("test.txt:3:9"))], getErrorRanges(source));
+        assert_eq!(vec!["cannot infer type of variable 'v' from value of type 'void'"], getErrorMessages(source));
+    }
+
+    #[test]
+    fn testCastErrors() {
+        assert_eq!(vec!["cannot cast 'String' to 'int'"], getErrorMessages("print(\"s\" as int)\n"));
+        assert_eq!(vec!["cannot cast 'float' to 'bool'"], getErrorMessages("print(1.0 as bool)\n"));
+        assert_eq!(vec!["unknown type 'foo'"], getErrorMessages("print(1 as foo)\n"));
+    }
+
+    #[test]
+    fn testTupleIndexErrors() {
+        let source = "\
+(int, (int, float)) t = (1, (2, 3.5))
+print(t.2)
+print(t.1.2)
+print(t.0.0)
+print(t.1.1.0)
+";
+        assert_eq!(vec![
+            ("UnknownVariable", format!("2"), format!("test.txt:2:9")),
+            ("UnknownVariable", format!("1.2"), format!("test.txt:3:9")),
+            ("UnknownVariable", format!("0.0"), format!("test.txt:4:9")),
+            ("UnknownVariable", format!("0"), format!("test.txt:5:13")),
+        ], getErrorRanges(source));
+        assert_eq!(vec![
+            "unknown variable '(int, (int, float)).2'",
+            "unknown variable '(int, float).2'",
+            "unknown variable 'int.0'",
+            "unknown variable 'float.0'",
+        ], getErrorMessages(source));
+    }
+
+    #[test]
+    fn testNonExhaustiveMatch() {
+        let source = "\
+enum Color { Red, Green, Blue }
+Color color = Color.Red
+match color {
+    Red: print(1)
+}
+match color {
+    Red: print(1)
+    Green: print(2)
+    else: print(3)
+}
+";
+        assert_eq!(vec![("NonExhaustiveMatch", format!("color"), format!("test.txt:3:7"))], getErrorRanges(source));
+        assert_eq!(vec!["match on 'Color' is not exhaustive (missing 'Green', 'Blue')"], getErrorMessages(source));
+    }
+
+    #[test]
+    fn testReservedMainFunction() {
+        let errorVec = getResolutionErrors("\
+void main() {
+}
+");
+        assert!(matches!(errorVec.as_slice(), [ResolutionError::ReservedFunctionName(_, name)] if name == "main"), "{errorVec:?}");
+        assert!(getResolutionErrors("void main_0() {\n}\n").iter().any(|error| matches!(error, ResolutionError::ReservedFunctionName(..))));
+        assert!(getResolutionErrors("void mainLoop() {\n}\n").is_empty());
+    }
+}
@@ -5,11 +5,15 @@ use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 use once_cell::sync::Lazy;
 
+use crate::module::FileRange;
 use crate::resolver::exporttable::incompleteexporttable::IncompleteExportTable;
 use crate::resolver::function::Function;
 use crate::resolver::resolutionerror::ResolutionError;
 use crate::resolver::typefunctioninfo::TypeFunctionInfo;
+use crate::resolver::typeinfo::array::{ArrayType, splitArrayTypeName};
+use crate::resolver::typeinfo::tuple::{splitTupleTypeName, TupleType};
 use crate::resolver::typeinfo::Type;
+use crate::resolver::typeinfo::void::VOID_TYPE;
 
 pub mod coreexporttable;
 
@@ -53,40 +57,70 @@ impl CompleteExportTable {
         return self.typeFunctionInfo.get(&ty).unwrap_or(EMPTY_FUNCTION_INFO.deref()).to_owned();
     }
 
-    pub fn getExportedType(&self, name: &str) -> Result<Type, ResolutionError> {
+    // range is used for errors, and should be the source referencing the type
+    pub fn getExportedType(&self, name: &str, range: &FileRange) -> Result<Type, ResolutionError> {
+        // array types are created from their element type (int[2][3] is an array of two int[3])
+        if let Some((elementName, suffix)) = splitArrayTypeName(name) {
+            let (length, remaining) = suffix.split_once(']').ok_or_else(|| ResolutionError::UnknownType(range.to_owned(), name.to_owned()))?;
+            let length = length.trim().parse::<usize>().map_err(|_| ResolutionError::UnknownType(range.to_owned(), name.to_owned()))?;
+            let elementType = self.getExportedType(&format!("{}{}", elementName.trim(), remaining.trim()), range)?;
+            if elementType == VOID_TYPE {
+                return Err(ResolutionError::InvalidOperationType(range.to_owned(), elementType, format!("array elements cannot be of type")));
+            }
+            return Ok(ArrayType::new(elementType, length));
+        }
+
+        // tuple types are created from their element types
+        if let Some(elementNames) = splitTupleTypeName(name.trim()) {
+            if elementNames.len() < 2 {
+                return Err(ResolutionError::UnknownType(range.to_owned(), name.to_owned()));
+            }
+            let mut elementTypes = Vec::new();
+            for elementName in elementNames {
+                let elementType = self.getExportedType(elementName, range)?;
+                if elementType == VOID_TYPE {
+                    return Err(ResolutionError::InvalidOperationType(range.to_owned(), elementType, format!("tuple elements cannot be of type")));
+                }
+                elementTypes.push(elementType);
+            }
+            return Ok(TupleType::new(elementTypes));
+        }
+
         let mut ty = self.exportTypes.get(name).map(|ty| ty.to_owned());
 
         for table in &self.dependencies {
             if let Some(importedType) = table.exportTypes.get(name) {
                 if let Some(ty) = ty {
-                    return Err(ResolutionError::ConflictingType(ty, importedType.to_owned()));
+                    return Err(ResolutionError::ConflictingType(range.to_owned(), ty, importedType.to_owned()));
                 }
                 ty = Some(importedType.to_owned());
             }
         }
 
-        return ty.ok_or(ResolutionError::UnknownType(name.to_owned()));
+        return ty.ok_or_else(|| ResolutionError::UnknownType(range.to_owned(), name.to_owned()));
     }
 
-    pub fn getExportedFunction(&self, name: &str) -> Result<Function, ResolutionError> {
+    // range is used for errors, and should be the source referencing the function
+    pub fn getExportedFunction(&self, name: &str, range: &FileRange) -> Result<Function, ResolutionError> {
         let mut function = self.exportFunctions.get(name).map(|function| function.to_owned());
 
         for table in &self.dependencies {
             if let Some(importedFunction) = table.exportFunctions.get(name) {
                 if let Some(function) = function {
-                    return Err(ResolutionError::ConflictingFunction(function, importedFunction.to_owned()));
+                    return Err(ResolutionError::ConflictingFunction(range.to_owned(), function, importedFunction.to_owned()));
                 }
                 function = Some(importedFunction.to_owned());
             }
         }
 
-        return function.ok_or(ResolutionError::UnknownFunction(name.to_owned()));
+        return function.ok_or_else(|| ResolutionError::UnknownFunction(range.to_owned(), name.to_owned()));
     }
 
-    pub fn addExportedType(&mut self, ty: Type) -> Result<(), ResolutionError> {
+    // returns the existing type if a type with the same name has already been exported
+    pub fn addExportedType(&mut self, ty: Type) -> Result<(), Type> {
         match self.exportTypes.entry(ty.getTypeName().to_owned()) {
             Entry::Occupied(entry) => {
-                Err(ResolutionError::ConflictingType(entry.get().to_owned(), ty))
+                Err(entry.get().to_owned())
             }
             Entry::Vacant(entry) => {
                 entry.insert(ty);
@@ -95,10 +129,11 @@ impl CompleteExportTable {
         }
     }
 
-    pub fn addExportedFunction(&mut self, function: Function) -> Result<(), ResolutionError> {
+    // returns the existing function if a function with the same name has already been exported
+    pub fn addExportedFunction(&mut self, function: Function) -> Result<(), Function> {
         match self.exportFunctions.entry(function.getFunctionName().to_owned()) {
             Entry::Occupied(entry) => {
-                Err(ResolutionError::ConflictingFunction(entry.get().to_owned(), function))
+                Err(entry.get().to_owned())
             }
             Entry::Vacant(entry) => {
                 entry.insert(function);
@@ -3,27 +3,36 @@ use std::fmt::Debug;
 use hashbrown::{HashMap, HashSet};
 
 use crate::ast::symbol::classdefinition::ClassDefinitionSym;
+use crate::ast::symbol::enumdefinition::EnumDefinitionSym;
+use crate::ast::symbol::expr::Expr;
 use crate::ast::symbol::function::FunctionDefinitionSym;
 use crate::ast::symbol::Symbol;
 use crate::ast::SymbolPos;
 use crate::ast::visibility::Visibility;
+use crate::module::FileRange;
 use crate::resolver::exporttable::completeexporttable::CompleteExportTable;
 use crate::resolver::function::{Function, Parameter};
 use crate::resolver::resolutionerror::ResolutionError;
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::typefunctioninfo::TypeFunctionInfo;
 use crate::resolver::typeinfo::class::ClassTypeInfo;
+use crate::resolver::typeinfo::r#enum::EnumTypeInfo;
 use crate::resolver::typeinfo::Type;
 
 #[derive(Debug)]
 struct IncompleteFunctionParameter {
     typeName: String,
+    typeRange: FileRange,
     name: String,
+    range: FileRange,
 }
 
 #[derive(Debug)]
 struct IncompleteFunction {
     name: String,
+    range: FileRange,
     returnType: String,
+    returnTypeRange: FileRange,
     visibility: Visibility,
     parameters: Vec<IncompleteFunctionParameter>,
 }
@@ -31,18 +40,22 @@ struct IncompleteFunction {
 impl IncompleteFunction {
     fn new(functionDefinition: &FunctionDefinitionSym) -> Result<Self, ResolutionError> {
         if functionDefinition.parameters.iter().any(|parameter| parameter.defaultExpr.is_some()) {
-            return Err(ResolutionError::Unsupported(functionDefinition.range.getStartPos(), "unsupported default function parameters".to_owned()));
+            return Err(ResolutionError::Unsupported(functionDefinition.range.getFileRange(), "default function parameters".to_owned()));
         }
         if !functionDefinition.attributeVec.is_empty() {
-            return Err(ResolutionError::Unsupported(functionDefinition.range.getStartPos(), format!("attributes unsupported: {:?}", functionDefinition.attributeVec)));
+            return Err(ResolutionError::Unsupported(functionDefinition.range.getFileRange(), format!("attributes ({:?})", functionDefinition.attributeVec)));
         }
         return Ok(Self {
             name: functionDefinition.functionName.getToken().getSourceRange().getSourceInRange().to_owned(),
-            returnType: functionDefinition.returnType.getToken().getSourceRange().getSourceInRange().to_string(),
+            range: functionDefinition.functionName.getFileRange(),
+            returnType: functionDefinition.returnType.getFileRange().getSourceInRange().to_string(),
+            returnTypeRange: functionDefinition.returnType.getFileRange(),
             visibility: functionDefinition.visibility,
             parameters: functionDefinition.parameters.iter().map(|parameter| IncompleteFunctionParameter {
-                typeName: parameter.typeName.getToken().getSourceRange().getSourceInRange().to_owned(),
+                typeName: parameter.typeName.getFileRange().getSourceInRange().to_owned(),
+                typeRange: parameter.typeName.getFileRange(),
                 name: parameter.parameterName.getToken().getSourceRange().getSourceInRange().to_owned(),
+                range: parameter.parameterName.getFileRange(),
             }).collect(),
         });
     }
@@ -50,13 +63,30 @@ impl IncompleteFunction {
 
 #[derive(Debug)]
 struct IncompleteField {
-    typeName: String,
+    // None if the type is inferred from the default value
+    typeName: Option<String>,
+    typeRange: FileRange,
     name: String,
+    range: FileRange,
+    defaultValue: Option<ResolvedExpr>,
+}
+
+// field default values are currently limited to literals, which can be resolved without a scope
+fn getLiteralValue(expr: &Expr) -> Result<ResolvedExpr, ResolutionError> {
+    return match expr {
+        Expr::LiteralBool(expr) => Ok(ResolvedExpr::LiteralBool(expr.value)),
+        Expr::LiteralChar(expr) => Ok(ResolvedExpr::LiteralChar(expr.value)),
+        Expr::LiteralFloat(expr) => Ok(ResolvedExpr::LiteralFloat(expr.value)),
+        Expr::LiteralInteger(expr) => Ok(ResolvedExpr::LiteralInteger(expr.value)),
+        Expr::LiteralString(expr) => Ok(ResolvedExpr::LiteralString(expr.fileRange.getSourceInRange().to_owned())),
+        _ => Err(ResolutionError::Unsupported(expr.getRange().getFileRange(), format!("non-literal default values"))),
+    };
 }
 
 #[derive(Debug)]
 struct IncompleteClass {
     name: String,
+    range: FileRange,
     fields: Vec<IncompleteField>,
     functions: Vec<IncompleteFunction>,
 }
@@ -64,14 +94,27 @@ struct IncompleteClass {
 impl IncompleteClass {
     fn new(classDefinition: &ClassDefinitionSym) -> Result<Self, ResolutionError> {
         if classDefinition.visibility != Visibility::Private {
-            return Err(ResolutionError::Unsupported(classDefinition.range.getStartPos(), format!("unsupported visibility modifier on class")));
+            return Err(ResolutionError::Unsupported(classDefinition.range.getFileRange(), format!("visibility modifiers on classes")));
         }
         return Ok(Self {
             name: classDefinition.name.getToken().getSourceRange().getSourceInRange().to_owned(),
-            fields: classDefinition.fields.iter().map(|v| IncompleteField {
-                typeName: v.typeName.to_owned().unwrap().getToken().getSourceRange().getSourceInRange().to_owned(),
-                name: v.name.getToken().getSourceRange().getSourceInRange().to_owned(),
-            }).collect(),
+            range: classDefinition.name.getFileRange(),
+            fields: {
+                let mut fields = Vec::new();
+                for field in &classDefinition.fields {
+                    fields.push(IncompleteField {
+                        typeName: field.typeName.as_ref().map(|typeName| typeName.getFileRange().getSourceInRange().to_owned()),
+                        typeRange: field.typeName.as_ref().map(|typeName| typeName.getFileRange()).unwrap_or(field.name.getFileRange()),
+                        name: field.name.getToken().getSourceRange().getSourceInRange().to_owned(),
+                        range: field.name.getFileRange(),
+                        defaultValue: match &field.defaultValue {
+                            Some(value) => Some(getLiteralValue(value)?),
+                            None => None,
+                        },
+                    });
+                }
+                fields
+            },
             functions: {
                 let mut methods = Vec::new();
                 for method in &classDefinition.methods {
@@ -83,9 +126,31 @@ impl IncompleteClass {
     }
 }
 
+#[derive(Debug)]
+struct IncompleteEnum {
+    name: String,
+    range: FileRange,
+    // variant name and range, in declaration order
+    variants: Vec<(String, FileRange)>,
+}
+
+impl IncompleteEnum {
+    fn new(enumDefinition: &EnumDefinitionSym) -> Result<Self, ResolutionError> {
+        if enumDefinition.visibility != Visibility::Private {
+            return Err(ResolutionError::Unsupported(enumDefinition.range.getFileRange(), format!("visibility modifiers on enums")));
+        }
+        return Ok(Self {
+            name: enumDefinition.name.getToken().getSourceRange().getSourceInRange().to_owned(),
+            range: enumDefinition.name.getFileRange(),
+            variants: enumDefinition.variants.iter().map(|variant| (variant.getToken().getSourceRange().getSourceInRange().to_owned(), variant.getFileRange())).collect(),
+        });
+    }
+}
+
 #[derive(Debug)]
 pub struct IncompleteExportTable {
     classVec: Vec<IncompleteClass>,
+    enumVec: Vec<IncompleteEnum>,
     functionVec: Vec<IncompleteFunction>,
 }
 
@@ -93,12 +158,14 @@ impl IncompleteExportTable {
     pub fn new() -> Self {
         return Self {
             classVec: Vec::new(),
+            enumVec: Vec::new(),
             functionVec: Vec::new(),
         };
     }
 
     pub fn merge(&mut self, mut other: Self) {
         self.classVec.append(&mut other.classVec);
+        self.enumVec.append(&mut other.enumVec);
         self.functionVec.append(&mut other.functionVec);
     }
 
@@ -106,18 +173,23 @@ impl IncompleteExportTable {
         match symbolPos.getSymbol() {
             Symbol::ClassDefinition(definition) => {
                 if !definition.staticFields.is_empty() {
-                    return Err(ResolutionError::Unsupported(symbolPos.getModulePos(), "static fields".to_owned()));
-                }
-                if let Some(field) = definition.fields.iter().find(|field| field.defaultValue.is_some()) {
-                    return Err(ResolutionError::Unsupported(field.name.to_owned(), "default values".to_owned()));
+                    return Err(ResolutionError::Unsupported(definition.staticFields[0].name.getFileRange(), "static fields".to_owned()));
                 }
                 if let Some(field) = definition.fields.iter().find(|field| field.typeName.is_none() && field.defaultValue.is_none()) {
-                    return Err(ResolutionError::ResolutionClassField(field.name.to_owned()));
+                    return Err(ResolutionError::ResolutionClassField(field.name.getFileRange()));
                 }
                 self.classVec.push(IncompleteClass::new(definition)?);
             }
+            Symbol::EnumDefinition(definition) => {
+                self.enumVec.push(IncompleteEnum::new(definition)?);
+            }
             Symbol::FunctionDefinition(definition) => {
-                self.functionVec.push(IncompleteFunction::new(definition)?);
+                let function = IncompleteFunction::new(definition)?;
+                // function names are emitted as written, and would conflict with the program entry point (see CompiledModule)
+                if function.name == "main" || function.name.strip_prefix("main_").is_some_and(|id| id.parse::<usize>().is_ok()) {
+                    return Err(ResolutionError::ReservedFunctionName(function.range, function.name));
+                }
+                self.functionVec.push(function);
             }
             _ if self.isExported(&symbolPos) => unimplemented!("missing export handle for {:?}", symbolPos.getSymbol()),
             _ => panic!("cannot export symbol {:?}", symbolPos.getSymbol()),
@@ -138,15 +210,15 @@ impl IncompleteExportTable {
 
     pub fn isExportable(symbolPos: &SymbolPos) -> bool {
         return match symbolPos.getSymbol() {
-            Symbol::ClassDefinition(_) | Symbol::FunctionDefinition(_) => true,
+            Symbol::ClassDefinition(_) | Symbol::EnumDefinition(_) | Symbol::FunctionDefinition(_) => true,
             _ => false
         };
     }
 
     pub fn complete(mut self, table: &mut CompleteExportTable) -> Result<(), Vec<ResolutionError>> {
         fn resolveFunction(errorVec: &mut Vec<ResolutionError>, function: IncompleteFunction, table: &CompleteExportTable, class: Option<Type>) -> Option<Function> {
-            fn getExported(errorVec: &mut Vec<ResolutionError>, typeName: &String, table: &CompleteExportTable) -> Option<Type> {
-                return match table.getExportedType(typeName) {
+            fn getExported(errorVec: &mut Vec<ResolutionError>, typeName: &String, range: &FileRange, table: &CompleteExportTable) -> Option<Type> {
+                return match table.getExportedType(typeName, range) {
                     Ok(ty) => Some(ty),
                     Err(error) => {
                         errorVec.push(error);
@@ -155,7 +227,7 @@ impl IncompleteExportTable {
                 };
             }
 
-            let returnType = getExported(errorVec, &function.returnType, table)?;
+            let returnType = getExported(errorVec, &function.returnType, &function.returnTypeRange, table)?;
             let mut parameterVec = Vec::new();
 
             if let Some(ty) = class {
@@ -165,29 +237,44 @@ impl IncompleteExportTable {
                 })
             }
 
-            if function.parameters.len() != function.parameters.iter().map(|parameter| &parameter.name).collect::<HashSet<_>>().len() {
-                errorVec.push(ResolutionError::ConflictingParameterName(function.name));
+            let mut parameterNames = HashSet::new();
+            if let Some(parameter) = function.parameters.iter().find(|parameter| !parameterNames.insert(parameter.name.to_owned())) {
+                errorVec.push(ResolutionError::ConflictingParameterName(parameter.range.to_owned(), function.name.to_owned()));
                 return None;
             }
 
             for parameter in function.parameters {
                 parameterVec.push(Parameter {
-                    ty: getExported(errorVec, &parameter.typeName, table)?,
+                    ty: getExported(errorVec, &parameter.typeName, &parameter.typeRange, table)?,
                     name: parameter.name,
                 })
             }
 
-            return Some(Function::new(function.name, function.visibility, returnType, parameterVec));
+            return Some(Function::newWithRange(function.name, function.visibility, returnType, parameterVec, Some(function.range)));
         }
 
         let mut errorVec = Vec::new();
 
+        // enums do not depend on other types, and are exported before classes so that fields may use them
+        for enumDefinition in self.enumVec {
+            let mut enumType = EnumTypeInfo::newBuilder(enumDefinition.name.to_owned());
+            for (variant, range) in enumDefinition.variants {
+                if enumType.addVariant(variant.to_owned()).is_err() {
+                    errorVec.push(ResolutionError::ConflictingVariants(range, enumDefinition.name.to_owned(), variant));
+                }
+            }
+            let ty = enumType.build();
+            if let Err(existing) = table.addExportedType(ty.to_owned()) {
+                errorVec.push(ResolutionError::ConflictingType(enumDefinition.range, existing, ty));
+            }
+        }
+
         let mut exportClasses = HashMap::new();
         let mut index = 0;
         while index < self.classVec.len() {
             let class = &mut self.classVec[index];
             if exportClasses.insert(class.name.to_owned(), ClassTypeInfo::newBuilder(class.name.to_owned())).is_some() {
-                errorVec.push(ResolutionError::ConflictingTypeDefinition(class.name.to_owned()));
+                errorVec.push(ResolutionError::ConflictingTypeDefinition(class.range.to_owned(), class.name.to_owned()));
                 self.classVec.swap_remove(index);
             } else {
                 index += 1;
@@ -202,24 +289,34 @@ impl IncompleteExportTable {
             while index < self.classVec.len() {
                 let class = &mut self.classVec[index];
                 class.fields.retain(|field| {
-                    let fieldType = table.getExportedType(&field.typeName);
+                    let fieldType = match &field.typeName {
+                        Some(typeName) => table.getExportedType(typeName, &field.typeRange),
+                        None => Ok(field.defaultValue.as_ref().unwrap().getExpressionType()),
+                    };
                     return match fieldType {
                         Ok(ty) => {
-                            if let Err(error) = exportClasses.get_mut(&class.name.to_owned()).unwrap().addFieldFrom(ty, field.name.to_owned()) {
-                                errorVec.push(error);
+                            if let Some(defaultValue) = &field.defaultValue {
+                                if defaultValue.getExpressionType() != ty {
+                                    errorVec.push(ResolutionError::ExpectedType(field.range.to_owned(), ty, defaultValue.getExpressionType(), format!("mismatched type for default value of field '{}'", field.name)));
+                                    errorValue = true;
+                                    return false;
+                                }
+                            }
+                            if exportClasses.get_mut(&class.name.to_owned()).unwrap().addFieldFrom(ty, field.name.to_owned(), field.defaultValue.to_owned()).is_err() {
+                                errorVec.push(ResolutionError::ConflictingFields(field.range.to_owned(), class.name.to_owned(), field.name.to_owned()));
                                 errorValue = true;
                             }
                             false
                         }
                         Err(err) => {
-                            if exportClasses.contains_key(&field.typeName) {
+                            if exportClasses.contains_key(field.typeName.as_ref().unwrap()) {
                                 true
                             } else {
-                                if let ResolutionError::UnknownType(_) = err {
+                                if let ResolutionError::UnknownType(_, _) = err {
                                     true
                                 } else {
                                     if class.name == field.name {
-                                        errorVec.push(ResolutionError::CircularDependencies(vec![field.name.to_owned()]));
+                                        errorVec.push(ResolutionError::CircularDependencies(field.typeRange.to_owned(), vec![field.name.to_owned()]));
                                     } else {
                                         errorVec.push(err);
                                     }
@@ -234,8 +331,8 @@ impl IncompleteExportTable {
                 if class.fields.is_empty() || errorValue {
                     let class = self.classVec.swap_remove(index);
                     let ty = exportClasses.remove(&class.name).unwrap().build();
-                    if let Err(error) = table.addExportedType(ty.to_owned()) {
-                        errorVec.push(error);
+                    if let Err(existing) = table.addExportedType(ty.to_owned()) {
+                        errorVec.push(ResolutionError::ConflictingType(class.range, existing, ty));
                     } else {
                         classFunctionInfo.insert(ty, class.functions);
                     }
@@ -245,22 +342,25 @@ impl IncompleteExportTable {
             }
         }
 
-        if errorVec.is_empty() {
-            for (class, functions) in classFunctionInfo {
-                let mut classFunctions = TypeFunctionInfo::new();
-                functions.into_iter().for_each(|function| if let Some(function) = resolveFunction(&mut errorVec, function, table, Some(class.to_owned())) {
-                    if let Err(err) = classFunctions.addFunction(function) {
-                        errorVec.push(err);
+        // functions are resolved even if classes failed, so that all errors are reported
+        for (class, functions) in classFunctionInfo {
+            let mut classFunctions = TypeFunctionInfo::new();
+            functions.into_iter().for_each(|function| {
+                let range = function.range.to_owned();
+                if let Some(function) = resolveFunction(&mut errorVec, function, table, Some(class.to_owned())) {
+                    if let Err(existing) = classFunctions.addFunction(function.to_owned()) {
+                        errorVec.push(ResolutionError::ConflictingFunction(range, existing, function));
                     }
-                });
-                table.setTypeFunctionInfo(class, classFunctions);
-            }
+                }
+            });
+            table.setTypeFunctionInfo(class, classFunctions);
+        }
 
-            for function in self.functionVec {
-                if let Some(function) = resolveFunction(&mut errorVec, function, table, None) {
-                    if let Err(err) = table.addExportedFunction(function) {
-                        errorVec.push(err);
-                    }
+        for function in self.functionVec {
+            let range = function.range.to_owned();
+            if let Some(function) = resolveFunction(&mut errorVec, function, table, None) {
+                if let Err(existing) = table.addExportedFunction(function.to_owned()) {
+                    errorVec.push(ResolutionError::ConflictingFunction(range, existing, function));
                 }
             }
         }
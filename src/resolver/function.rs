@@ -6,6 +6,7 @@ use std::sync::Arc;
 use std::sync::atomic::AtomicUsize;
 
 use crate::ast::visibility::Visibility;
+use crate::module::FileRange;
 use crate::resolver::typeinfo::Type;
 
 #[derive(Debug)]
@@ -20,6 +21,8 @@ pub struct FunctionImpl {
     pub visibility: Visibility,
     pub parameters: Vec<Parameter>,
     pub id: usize,
+    // name of the function in its definition (None for functions without source, such as sdk functions)
+    pub range: Option<FileRange>,
 }
 
 impl FunctionImpl {
@@ -33,6 +36,10 @@ pub struct Function(Arc<FunctionImpl>);
 
 impl Function {
     pub fn new(name: String, visibility: Visibility, returnType: Type, parameters: Vec<Parameter>) -> Self {
+        return Self::newWithRange(name, visibility, returnType, parameters, None);
+    }
+
+    pub fn newWithRange(name: String, visibility: Visibility, returnType: Type, parameters: Vec<Parameter>, range: Option<FileRange>) -> Self {
         static NEXT_FUNCTION_ID: AtomicUsize = AtomicUsize::new(0);
 
         return Self {
@@ -42,6 +49,7 @@ impl Function {
                 visibility,
                 parameters,
                 id: NEXT_FUNCTION_ID.fetch_add(1, std::sync::atomic::Ordering::Relaxed),
+                range,
             }),
         };
     }
@@ -1,32 +1,115 @@
-use crate::module::modulepos::ModulePos;
+use strum_macros::IntoStaticStr;
+
+use crate::diagnostic::Diagnostic;
+use crate::module::FileRange;
 use crate::resolver::function::Function;
 use crate::resolver::typeinfo::Type;
 
+// every error holds the range of the source which caused the error
+#[derive(IntoStaticStr)]
 #[derive(Debug)]
 pub enum ResolutionError {
-    Unsupported(ModulePos, String),
-    Unexpected(ModulePos, String),
-    CircularDependencies(Vec<String>),
+    Unsupported(FileRange, String),
+    Unexpected(FileRange, String),
+    CircularDependencies(FileRange, Vec<String>),
     // class field declared with let, but no default expr provided
-    ResolutionClassField(ModulePos),
+    ResolutionClassField(FileRange),
     // operation cannot be applied to value
-    InvalidOperation(String),
+    InvalidOperation(FileRange, String),
     // operation cannot be applied to type
-    InvalidOperationType(Type, String),
-    ExpectedType(Type, Type, String),
-    UnknownType(String),
-    UnknownVariable(String),
-    UnknownFunction(String),
-    UnresolvedType(ModulePos, String),
-    ParameterMismatch(Function, String),
-    ConflictingVariable(String, String),
-    MissingReturn(String),
+    InvalidOperationType(FileRange, Type, String),
+    ExpectedType(FileRange, Type, Type, String),
+    UnknownType(FileRange, String),
+    UnknownVariable(FileRange, String),
+    UnknownFunction(FileRange, String),
+    // loop label
+    UnknownLabel(FileRange, String),
+    UnresolvedType(FileRange, String),
+    ParameterMismatch(FileRange, Function, String),
+    // conflicting variable (variable name, message, range of previous declaration)
+    ConflictingVariable(FileRange, String, String, FileRange),
+    MissingReturn(FileRange, String),
     // conflicting field name (type name, field name)
-    ConflictingFields(String, String),
-    ConflictingType(Type, Type),
-    ConflictingFunction(Function, Function),
+    ConflictingFields(FileRange, String, String),
+    ConflictingType(FileRange, Type, Type),
+    // conflicting function (previous function, function)
+    ConflictingFunction(FileRange, Function, Function),
     // function name
-    ConflictingParameterName(String),
+    ConflictingParameterName(FileRange, String),
     // type name
-    ConflictingTypeDefinition(String),
+    ConflictingTypeDefinition(FileRange, String),
+    // function name used by the generated program entry point
+    ReservedFunctionName(FileRange, String),
+    // conflicting variant name (type name, variant name)
+    ConflictingVariants(FileRange, String, String),
+    // variants without a case
+    NonExhaustiveMatch(FileRange, Type, Vec<String>),
+}
+
+impl ResolutionError {
+    pub fn getErrorMessage(&self) -> String {
+        return match self {
+            ResolutionError::Unsupported(_, message) => format!("unsupported: {message}"),
+            ResolutionError::Unexpected(_, message) => message.to_owned(),
+            ResolutionError::CircularDependencies(_, nameVec) => format!("circular dependency between {}", nameVec.iter().map(|name| format!("'{name}'")).collect::<Vec<_>>().join(", ")),
+            ResolutionError::ResolutionClassField(range) => format!("class field '{}' declared with let requires a default value", range.getSourceInRange()),
+            ResolutionError::InvalidOperation(_, message) => message.to_owned(),
+            ResolutionError::InvalidOperationType(_, ty, message) => format!("{message} '{}'", ty.getTypeName()),
+            ResolutionError::ExpectedType(_, expected, found, message) => format!("{message}: expected '{}', found '{}'", expected.getTypeName(), found.getTypeName()),
+            ResolutionError::UnknownType(_, name) => format!("unknown type '{name}'"),
+            ResolutionError::UnknownVariable(_, name) => format!("unknown variable '{name}'"),
+            ResolutionError::UnknownFunction(_, name) => format!("unknown function '{name}'"),
+            ResolutionError::UnknownLabel(_, name) => format!("unknown loop label '{name}'"),
+            ResolutionError::UnresolvedType(_, name) => format!("unable to resolve type '{name}'"),
+            ResolutionError::ParameterMismatch(_, function, message) => format!("{message} (calling '{}')", function.name),
+            ResolutionError::ConflictingVariable(_, name, message, _) => format!("{message} ('{name}')"),
+            ResolutionError::MissingReturn(_, message) => message.to_owned(),
+            ResolutionError::ConflictingFields(_, typeName, fieldName) => format!("conflicting field '{fieldName}' in class '{typeName}'"),
+            ResolutionError::ConflictingType(_, ty, _) => format!("conflicting definitions for type '{}'", ty.getTypeName()),
+            ResolutionError::ConflictingFunction(_, function, _) => format!("conflicting definitions for function '{}'", function.name),
+            ResolutionError::ConflictingParameterName(_, functionName) => format!("conflicting parameter names in function '{functionName}'"),
+            ResolutionError::ConflictingTypeDefinition(_, typeName) => format!("conflicting definitions for type '{typeName}'"),
+            ResolutionError::ReservedFunctionName(_, functionName) => format!("function name '{functionName}' is reserved for the program entry point"),
+            ResolutionError::ConflictingVariants(_, typeName, variantName) => format!("conflicting variant '{variantName}' in enum '{typeName}'"),
+            ResolutionError::NonExhaustiveMatch(_, ty, variantVec) => format!("match on '{}' is not exhaustive (missing {})", ty.getTypeName(), variantVec.iter().map(|variant| format!("'{variant}'")).collect::<Vec<_>>().join(", ")),
+        };
+    }
+
+    pub fn getFileRange(&self) -> &FileRange {
+        return match self {
+            ResolutionError::Unsupported(range, _) |
+            ResolutionError::Unexpected(range, _) |
+            ResolutionError::CircularDependencies(range, _) |
+            ResolutionError::ResolutionClassField(range) |
+            ResolutionError::InvalidOperation(range, _) |
+            ResolutionError::InvalidOperationType(range, _, _) |
+            ResolutionError::ExpectedType(range, _, _, _) |
+            ResolutionError::UnknownType(range, _) |
+            ResolutionError::UnknownVariable(range, _) |
+            ResolutionError::UnknownFunction(range, _) |
+            ResolutionError::UnknownLabel(range, _) |
+            ResolutionError::UnresolvedType(range, _) |
+            ResolutionError::ParameterMismatch(range, _, _) |
+            ResolutionError::ConflictingVariable(range, _, _, _) |
+            ResolutionError::MissingReturn(range, _) |
+            ResolutionError::ConflictingFields(range, _, _) |
+            ResolutionError::ConflictingType(range, _, _) |
+            ResolutionError::ConflictingFunction(range, _, _) |
+            ResolutionError::ConflictingParameterName(range, _) |
+            ResolutionError::ConflictingTypeDefinition(range, _) |
+            ResolutionError::ReservedFunctionName(range, _) |
+            ResolutionError::ConflictingVariants(range, _, _) |
+            ResolutionError::NonExhaustiveMatch(range, _, _) => range,
+        };
+    }
+
+    pub fn getDiagnostic(&self) -> Diagnostic {
+        let diagnostic = Diagnostic::error(self.getErrorMessage(), Some(self.getFileRange().to_owned())).withCode(self.into());
+        // conflicts point to the earlier declaration
+        return match self {
+            ResolutionError::ConflictingVariable(_, name, _, previousRange) => diagnostic.withNote(Diagnostic::note(format!("variable '{name}' previously declared here"), Some(previousRange.to_owned()))),
+            ResolutionError::ConflictingFunction(_, previous, _) if previous.range.is_some() => diagnostic.withNote(Diagnostic::note(format!("function '{}' previously defined here", previous.name), previous.range.to_owned())),
+            _ => diagnostic,
+        };
+    }
 }
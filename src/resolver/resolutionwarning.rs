@@ -0,0 +1,30 @@
+use strum_macros::IntoStaticStr;
+
+use crate::diagnostic::Diagnostic;
+use crate::module::FileRange;
+
+// warnings do not prevent compilation, and are reported alongside the compiled output
+#[derive(IntoStaticStr)]
+#[derive(Debug)]
+pub enum ResolutionWarning {
+    // first statement following a return, break or continue
+    UnreachableCode(FileRange),
+}
+
+impl ResolutionWarning {
+    pub fn getWarningMessage(&self) -> String {
+        return match self {
+            ResolutionWarning::UnreachableCode(_) => format!("unreachable code"),
+        };
+    }
+
+    pub fn getFileRange(&self) -> &FileRange {
+        return match self {
+            ResolutionWarning::UnreachableCode(range) => range,
+        };
+    }
+
+    pub fn getDiagnostic(&self) -> Diagnostic {
+        return Diagnostic::warning(self.getWarningMessage(), Some(self.getFileRange().to_owned())).withCode(self.into());
+    }
+}
@@ -7,7 +7,10 @@ pub mod ifstatement;
 pub mod resolvedexpr;
 pub mod statement;
 pub mod whilestatement;
+pub mod forstatement;
+pub mod loopstatement;
 pub mod variabledeclare;
+pub mod variableinitialize;
 pub mod returnstatement;
 pub mod resolvedoperator;
 pub mod resolvedvariable;
@@ -19,6 +22,16 @@ pub mod defaultclass;
 pub mod printstatement;
 pub mod defaultpointer;
 pub mod readexpr;
+pub mod resolvedcast;
+pub mod breakstatement;
+pub mod continuestatement;
+pub mod literalarray;
+pub mod resolvedindex;
+pub mod arraylength;
+pub mod literaltuple;
+pub mod tupleinitialize;
+pub mod literalenum;
+pub mod matchstatement;
 
 static NEXT_VARIABLE_ID: AtomicUsize = AtomicUsize::new(0);
 
@@ -0,0 +1,20 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
+use crate::resolver::typeinfo::Type;
+
+// length of an array value which may have side effects (e.g. f().length)
+// the value is evaluated, but the length is known at compile time
+#[derive(Debug, Clone)]
+pub struct ArrayLength {
+    pub value: ResolvedExpr,
+    pub length: usize,
+}
+
+impl StatementType for ArrayLength {}
+
+impl ResolvedExprType for ArrayLength {
+    fn getExpressionType(&self) -> Type {
+        return INTEGER_TYPE.to_owned();
+    }
+}
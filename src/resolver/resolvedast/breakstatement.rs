@@ -0,0 +1,9 @@
+use crate::resolver::resolvedast::statement::StatementType;
+
+#[derive(Debug)]
+pub struct BreakStatement {
+    // id of the loop being exited
+    pub loopId: usize,
+}
+
+impl StatementType for BreakStatement {}
@@ -0,0 +1,9 @@
+use crate::resolver::resolvedast::statement::StatementType;
+
+#[derive(Debug)]
+pub struct ContinueStatement {
+    // id of the loop being continued
+    pub loopId: usize,
+}
+
+impl StatementType for ContinueStatement {}
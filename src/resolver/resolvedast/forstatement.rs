@@ -0,0 +1,17 @@
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::resolvedast::statement::{Statement, StatementType};
+use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+
+#[derive(Debug)]
+pub struct ForStatement {
+    // referenced by break and continue statements
+    pub id: usize,
+    pub variable: VariableDeclare,
+    // inclusive
+    pub start: ResolvedExpr,
+    // exclusive, evaluated once before the loop
+    pub end: ResolvedExpr,
+    pub statement: Statement,
+}
+
+impl StatementType for ForStatement {}
@@ -0,0 +1,18 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::Type;
+
+#[derive(Debug, Clone)]
+pub struct LiteralArray {
+    pub ty: Type,
+    // values are all of the element type, one value for each element of the array
+    pub valueVec: Vec<ResolvedExpr>,
+}
+
+impl StatementType for LiteralArray {}
+
+impl ResolvedExprType for LiteralArray {
+    fn getExpressionType(&self) -> Type {
+        return self.ty.to_owned();
+    }
+}
@@ -0,0 +1,18 @@
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExprType;
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::Type;
+
+#[derive(Debug, Clone)]
+pub struct LiteralEnum {
+    pub ty: Type,
+    // index of the variant in the enum definition
+    pub variant: usize,
+}
+
+impl StatementType for LiteralEnum {}
+
+impl ResolvedExprType for LiteralEnum {
+    fn getExpressionType(&self) -> Type {
+        return self.ty.to_owned();
+    }
+}
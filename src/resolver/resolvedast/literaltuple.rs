@@ -0,0 +1,18 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::Type;
+
+#[derive(Debug, Clone)]
+pub struct LiteralTuple {
+    pub ty: Type,
+    // one value for each element type of the tuple
+    pub valueVec: Vec<ResolvedExpr>,
+}
+
+impl StatementType for LiteralTuple {}
+
+impl ResolvedExprType for LiteralTuple {
+    fn getExpressionType(&self) -> Type {
+        return self.ty.to_owned();
+    }
+}
@@ -0,0 +1,10 @@
+use crate::resolver::resolvedast::statement::{Statement, StatementType};
+
+#[derive(Debug)]
+pub struct LoopStatement {
+    // referenced by break and continue statements
+    pub id: usize,
+    pub statement: Statement,
+}
+
+impl StatementType for LoopStatement {}
@@ -0,0 +1,25 @@
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::resolvedast::statement::{Statement, StatementType};
+
+#[derive(Debug)]
+pub struct MatchCase {
+    pub variant: usize,
+    pub statement: Statement,
+}
+
+// every variant of the enum is handled by a case or the else statement
+#[derive(Debug)]
+pub struct MatchStatement {
+    pub value: ResolvedExpr,
+    pub caseVec: Vec<MatchCase>,
+    pub elseStatement: Option<Statement>,
+}
+
+impl MatchStatement {
+    // statements of every branch the match may take
+    pub fn getStatements(&self) -> impl Iterator<Item = &Statement> {
+        return self.caseVec.iter().map(|case| &case.statement).chain(self.elseStatement.iter());
+    }
+}
+
+impl StatementType for MatchStatement {}
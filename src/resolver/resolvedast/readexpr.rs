@@ -3,7 +3,7 @@ use crate::resolver::resolvedast::statement::StatementType;
 use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
 use crate::resolver::typeinfo::Type;
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct ReadExpr {}
 
 impl StatementType for ReadExpr {}
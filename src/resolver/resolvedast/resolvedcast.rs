@@ -0,0 +1,18 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::Type;
+
+#[derive(Debug, Clone)]
+pub struct ResolvedCast {
+    pub value: ResolvedExpr,
+    // must be one of the explicit conversions of the value type
+    pub ty: Type,
+}
+
+impl StatementType for ResolvedCast {}
+
+impl ResolvedExprType for ResolvedCast {
+    fn getExpressionType(&self) -> Type {
+        return self.ty.to_owned();
+    }
+}
@@ -3,16 +3,24 @@ use std::ops::Deref;
 
 use once_cell::sync::Lazy;
 
+use crate::resolver::resolvedast::arraylength::ArrayLength;
 use crate::resolver::resolvedast::defaultclass::DefaultClass;
 use crate::resolver::resolvedast::defaultpointer::DefaultPointer;
 use crate::resolver::resolvedast::defaultvalue::DefaultValue;
 use crate::resolver::resolvedast::functioncall::FunctionCall;
+use crate::resolver::resolvedast::literalarray::LiteralArray;
+use crate::resolver::resolvedast::literalenum::LiteralEnum;
+use crate::resolver::resolvedast::literaltuple::LiteralTuple;
 use crate::resolver::resolvedast::readexpr::ReadExpr;
+use crate::resolver::resolvedast::resolvedcast::ResolvedCast;
+use crate::resolver::resolvedast::resolvedindex::ResolvedIndex;
 use crate::resolver::resolvedast::resolvedoperator::ResolvedOperator;
 use crate::resolver::resolvedast::resolvedproperty::ResolvedProperty;
 use crate::resolver::resolvedast::resolvedvariable::ResolvedVariable;
 use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::resolvedast::tupleinitialize::TupleInitialize;
 use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+use crate::resolver::resolvedast::variableinitialize::VariableInitialize;
 use crate::resolver::typeinfo::primitive::boolean::BOOLEAN_TYPE;
 use crate::resolver::typeinfo::primitive::character::CHARACTER_TYPE;
 use crate::resolver::typeinfo::primitive::float::FLOAT_TYPE;
@@ -55,15 +63,20 @@ impl ResolvedExprType for ResolvedExprTypeValue {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ResolvedExpr {
     Operator(Box<ResolvedOperator>),
+    Cast(Box<ResolvedCast>),
     FunctionCall(Box<FunctionCall>),
     Read(ReadExpr),
     // ConstructorCall(Box<ConstructorCall>),
     VariableDeclaration(VariableDeclare),
+    VariableInitialize(Box<VariableInitialize>),
+    TupleInitialize(Box<TupleInitialize>),
     Variable(ResolvedVariable),
     Property(Box<ResolvedProperty>),
+    Index(Box<ResolvedIndex>),
+    ArrayLength(Box<ArrayLength>),
     DefaultValue(DefaultValue),
     DefaultPointer(DefaultPointer),
     DefaultClass(DefaultClass),
@@ -72,19 +85,30 @@ pub enum ResolvedExpr {
     LiteralFloat(f64),
     LiteralInteger(i64),
     LiteralString(String),
+    LiteralArray(Box<LiteralArray>),
+    LiteralTuple(Box<LiteralTuple>),
+    LiteralEnum(LiteralEnum),
 }
 
 impl ResolvedExpr {
     pub fn getResolvedExprType(&self) -> &dyn ResolvedExprType {
         return match self {
             ResolvedExpr::Operator(v) => v.deref(),
+            ResolvedExpr::Cast(v) => v.deref(),
             ResolvedExpr::FunctionCall(v) => v.deref(),
             ResolvedExpr::VariableDeclaration(v) => v,
+            ResolvedExpr::VariableInitialize(v) => v.deref(),
+            ResolvedExpr::TupleInitialize(v) => v.deref(),
             ResolvedExpr::DefaultValue(v) => v,
             ResolvedExpr::DefaultClass(v) => v,
             ResolvedExpr::DefaultPointer(v) => v,
             ResolvedExpr::Variable(v) => v,
             ResolvedExpr::Property(v) => v.deref(),
+            ResolvedExpr::Index(v) => v.deref(),
+            ResolvedExpr::ArrayLength(v) => v.deref(),
+            ResolvedExpr::LiteralArray(v) => v.deref(),
+            ResolvedExpr::LiteralTuple(v) => v.deref(),
+            ResolvedExpr::LiteralEnum(v) => v,
             ResolvedExpr::Read(_) => {
                 static LITERAL_RESOLVED_EXPR_TYPE: Lazy<ResolvedExprTypeValue> = Lazy::new(|| ResolvedExprTypeValue::new(INTEGER_TYPE.to_owned(), false));
                 return LITERAL_RESOLVED_EXPR_TYPE.deref();
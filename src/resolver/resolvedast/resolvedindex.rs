@@ -0,0 +1,29 @@
+use crate::module::FileRange;
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::typeinfo::Type;
+
+#[derive(Debug, Clone)]
+pub struct ResolvedIndex {
+    // array or string
+    pub value: ResolvedExpr,
+    // integer index
+    pub index: ResolvedExpr,
+    // element type
+    pub ty: Type,
+    // source of the index expression, reported if the index is out of bounds
+    pub range: FileRange,
+}
+
+impl StatementType for ResolvedIndex {}
+
+impl ResolvedExprType for ResolvedIndex {
+    fn getExpressionType(&self) -> Type {
+        return self.ty.to_owned();
+    }
+
+    fn isAssignable(&self) -> bool {
+        // string contents are immutable
+        return self.value.getExpressionType().getArrayType().is_some() && self.value.getResolvedExprType().isAssignable();
+    }
+}
@@ -3,7 +3,7 @@ use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType}
 use crate::resolver::resolvedast::statement::StatementType;
 use crate::resolver::typeinfo::Type;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ResolvedOperator {
     pub operator: Operator,
     pub operands: Box<[ResolvedExpr]>,
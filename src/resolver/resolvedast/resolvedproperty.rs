@@ -4,7 +4,7 @@ use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType}
 use crate::resolver::resolvedast::statement::StatementType;
 use crate::resolver::typeinfo::{Type, TypeProperty};
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ResolvedProperty {
     pub value: ResolvedExpr,
     pub property: TypeProperty,
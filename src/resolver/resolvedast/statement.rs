@@ -1,6 +1,11 @@
 use std::fmt::Debug;
 
+use crate::resolver::resolvedast::breakstatement::BreakStatement;
+use crate::resolver::resolvedast::continuestatement::ContinueStatement;
+use crate::resolver::resolvedast::forstatement::ForStatement;
 use crate::resolver::resolvedast::ifstatement::IfStatement;
+use crate::resolver::resolvedast::loopstatement::LoopStatement;
+use crate::resolver::resolvedast::matchstatement::MatchStatement;
 use crate::resolver::resolvedast::printstatement::PrintStatement;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::resolvedast::resolvedfunctiondefinition::ResolvedFunctionDefinition;
@@ -13,7 +18,12 @@ pub trait StatementType: Debug {}
 #[derive(Debug)]
 pub enum Statement {
     If(Box<IfStatement>),
+    Match(Box<MatchStatement>),
     While(Box<WhileStatement>),
+    For(Box<ForStatement>),
+    Loop(Box<LoopStatement>),
+    Break(BreakStatement),
+    Continue(ContinueStatement),
     Return(ReturnStatement),
     Expr(ResolvedExpr),
     Print(PrintStatement),
@@ -21,3 +31,63 @@ pub enum Statement {
     Scope(ResolvedScope),
     Multiple(Vec<Statement>),
 }
+
+impl Statement {
+    // whether the statement contains a break out of the loop with the given id
+    pub fn containsBreak(&self, loopId: usize) -> bool {
+        return match self {
+            Statement::Break(statement) => statement.loopId == loopId,
+            Statement::If(statement) => statement.statement.containsBreak(loopId) || statement.elseStatement.as_ref().map_or(false, |statement| statement.containsBreak(loopId)),
+            Statement::Match(statement) => statement.getStatements().any(|statement| statement.containsBreak(loopId)),
+            Statement::While(statement) => statement.statement.containsBreak(loopId),
+            Statement::For(statement) => statement.statement.containsBreak(loopId),
+            Statement::Loop(statement) => statement.statement.containsBreak(loopId),
+            Statement::Scope(scope) => scope.statementVec.iter().any(|statement| statement.containsBreak(loopId)),
+            Statement::Multiple(statementVec) => statementVec.iter().any(|statement| statement.containsBreak(loopId)),
+            Statement::Continue(_) | Statement::Return(_) | Statement::Expr(_) | Statement::Print(_) | Statement::FunctionDefinition(_) => false,
+        };
+    }
+
+    // whether control never reaches the statement following this statement
+    pub fn isTerminating(&self) -> bool {
+        return match self {
+            Statement::Return(_) | Statement::Break(_) | Statement::Continue(_) => true,
+            Statement::If(statement) => statement.statement.isTerminating() && statement.elseStatement.as_ref().map_or(false, |statement| statement.isTerminating()),
+            // matches are exhaustive, so one of the branches is always taken
+            Statement::Match(statement) => statement.getStatements().all(|statement| statement.isTerminating()),
+            // infinite loops can only be exited by returning
+            Statement::While(statement) => matches!(statement.condition, ResolvedExpr::LiteralBool(true)) && !statement.statement.containsBreak(statement.id),
+            Statement::Loop(statement) => !statement.statement.containsBreak(statement.id),
+            Statement::Scope(scope) => scope.statementVec.iter().any(|statement| statement.isTerminating()),
+            Statement::Multiple(statementVec) => statementVec.iter().any(|statement| statement.isTerminating()),
+            Statement::For(_) | Statement::Expr(_) | Statement::Print(_) | Statement::FunctionDefinition(_) => false,
+        };
+    }
+
+    // whether every path through the statement returns from the function
+    // (as opposed to continuing to the next statement, or leaving a loop using break/continue)
+    pub fn alwaysReturns(&self) -> bool {
+        return match self {
+            Statement::Return(_) => true,
+            Statement::If(statement) => statement.statement.alwaysReturns() && statement.elseStatement.as_ref().map_or(false, |statement| statement.alwaysReturns()),
+            Statement::Match(statement) => statement.getStatements().all(|statement| statement.alwaysReturns()),
+            Statement::While(_) | Statement::Loop(_) => self.isTerminating(),
+            Statement::Scope(scope) => Self::allPathsReturn(&scope.statementVec),
+            Statement::Multiple(statementVec) => Self::allPathsReturn(statementVec),
+            Statement::Break(_) | Statement::Continue(_) | Statement::For(_) | Statement::Expr(_) | Statement::Print(_) | Statement::FunctionDefinition(_) => false,
+        };
+    }
+
+    pub fn allPathsReturn(statementVec: &[Statement]) -> bool {
+        for statement in statementVec {
+            if statement.alwaysReturns() {
+                return true;
+            }
+            if statement.isTerminating() {
+                // break or continue, statements following are unreachable
+                return false;
+            }
+        }
+        return false;
+    }
+}
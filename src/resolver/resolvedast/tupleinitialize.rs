@@ -0,0 +1,19 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+use crate::resolver::typeinfo::Type;
+
+// destructuring declaration (e.g. let (a, b) = f()), each variable is initialized with the tuple element at the same position
+#[derive(Debug, Clone)]
+pub struct TupleInitialize {
+    pub variableVec: Vec<VariableDeclare>,
+    pub value: ResolvedExpr,
+}
+
+impl StatementType for TupleInitialize {}
+
+impl ResolvedExprType for TupleInitialize {
+    fn getExpressionType(&self) -> Type {
+        return self.value.getExpressionType();
+    }
+}
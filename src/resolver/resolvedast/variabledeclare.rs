@@ -2,7 +2,7 @@ use crate::resolver::resolvedast::resolvedexpr::ResolvedExprType;
 use crate::resolver::resolvedast::statement::StatementType;
 use crate::resolver::typeinfo::Type;
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VariableDeclare {
     pub ty: Type,
     pub id: usize,
@@ -0,0 +1,19 @@
+use crate::resolver::resolvedast::resolvedexpr::{ResolvedExpr, ResolvedExprType};
+use crate::resolver::resolvedast::statement::StatementType;
+use crate::resolver::resolvedast::variabledeclare::VariableDeclare;
+use crate::resolver::typeinfo::Type;
+
+// declaration with initializer (e.g. int x = 5), variable is initialized with value instead of the default value of the type
+#[derive(Debug, Clone)]
+pub struct VariableInitialize {
+    pub variable: VariableDeclare,
+    pub value: ResolvedExpr,
+}
+
+impl StatementType for VariableInitialize {}
+
+impl ResolvedExprType for VariableInitialize {
+    fn getExpressionType(&self) -> Type {
+        return self.variable.ty.to_owned();
+    }
+}
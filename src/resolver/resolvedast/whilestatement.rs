@@ -3,6 +3,8 @@ use crate::resolver::resolvedast::statement::{Statement, StatementType};
 
 #[derive(Debug)]
 pub struct WhileStatement {
+    // referenced by break and continue statements
+    pub id: usize,
     pub condition: ResolvedExpr,
     pub statement: Statement,
 }
@@ -2,7 +2,6 @@ use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 
 use crate::resolver::function::Function;
-use crate::resolver::resolutionerror::ResolutionError;
 
 #[derive(Debug)]
 pub struct TypeFunctionInfo {
@@ -16,9 +15,10 @@ impl TypeFunctionInfo {
         };
     }
 
-    pub fn addFunction(&mut self, function: Function) -> Result<(), ResolutionError> {
+    // returns the existing function if a function with the same name has already been added
+    pub fn addFunction(&mut self, function: Function) -> Result<(), Function> {
         return match self.functionMap.entry(function.getFunctionName().to_owned()) {
-            Entry::Occupied(entry) => Err(ResolutionError::ConflictingFunction(function, entry.get().to_owned())),
+            Entry::Occupied(entry) => Err(entry.get().to_owned()),
             Entry::Vacant(v) => {
                 v.insert(function);
                 Ok(())
@@ -9,18 +9,27 @@ use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
 use once_cell::sync::Lazy;
 
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::typeinfo::array::ArrayType;
+use crate::resolver::typeinfo::r#enum::EnumTypeInfo;
+use crate::resolver::typeinfo::tuple::TupleType;
 
 pub mod void;
 pub mod primitive;
 pub mod class;
 pub mod string;
 pub mod pointer;
+pub mod error;
+pub mod array;
+pub mod tuple;
+pub mod r#enum;
 
 #[derive(Debug, Clone)]
 pub struct TypeProperty {
     pub ty: Type,
     pub name: String,
     pub index: usize,
+    // value used when the property is default initialized (instead of the default value of the type)
+    pub defaultValue: Option<ResolvedExpr>,
 }
 
 pub trait TypeInfo: Sync + Send {
@@ -37,6 +46,18 @@ pub trait TypeInfo: Sync + Send {
     fn isArithmeticType(&self) -> bool {
         return false;
     }
+
+    fn getArrayType(&self) -> Option<&ArrayType> {
+        return None;
+    }
+
+    fn getTupleType(&self) -> Option<&TupleType> {
+        return None;
+    }
+
+    fn getEnumType(&self) -> Option<&EnumTypeInfo> {
+        return None;
+    }
 }
 
 #[derive(Clone)]
@@ -0,0 +1,82 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use llvm_sys::core::LLVMArrayType;
+use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::resolver::resolvedast::literalarray::LiteralArray;
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::typeinfo::{Type, TypeInfo};
+
+// fixed length array of elements (e.g. int[4])
+pub struct ArrayType {
+    typeName: String,
+    pub elementType: Type,
+    pub length: usize,
+}
+
+impl ArrayType {
+    // types are compared by identity, so each element type and length pair is only created once
+    pub fn new(elementType: Type, length: usize) -> Type {
+        static ARRAY_TYPES: Lazy<Mutex<HashMap<(Type, usize), Type>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+        return ARRAY_TYPES.lock().entry((elementType.to_owned(), length)).or_insert_with(|| {
+            // int[2][3] is an array of two int[3]
+            let elementName = elementType.getTypeName();
+            let typeName = match splitArrayTypeName(elementName) {
+                Some((baseName, suffix)) => format!("{baseName}[{length}][{suffix}"),
+                None => format!("{elementName}[{length}]"),
+            };
+            Type(Arc::new(Self {
+                typeName,
+                elementType,
+                length,
+            }))
+        }).to_owned();
+    }
+}
+
+// splits an array type name at its first length (e.g. "int[2][3]" into "int" and "2][3]")
+// brackets within a tuple element type (e.g. "(int[2], float)") are not part of the outer type
+pub fn splitArrayTypeName(name: &str) -> Option<(&str, &str)> {
+    let mut depth = 0;
+    for (index, character) in name.char_indices() {
+        match character {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '[' if depth == 0 => return Some((&name[..index], &name[index + 1..])),
+            _ => {}
+        }
+    }
+    return None;
+}
+
+impl TypeInfo for ArrayType {
+    fn getTypeName(&self) -> &str {
+        return &self.typeName;
+    }
+
+    fn getLLVMType(&self, context: LLVMContextRef) -> LLVMTypeRef {
+        return unsafe {
+            LLVMArrayType(self.elementType.getLLVMType(context), self.length as _)
+        };
+    }
+
+    fn getExplicitConversions(&self) -> &Vec<Type> {
+        static EMPTY_VEC: Lazy<Vec<Type>> = Lazy::new(|| Vec::new());
+        return EMPTY_VEC.deref();
+    }
+
+    fn getDefaultValue(&self, ty: Type) -> ResolvedExpr {
+        return ResolvedExpr::LiteralArray(Box::new(LiteralArray {
+            ty,
+            valueVec: (0..self.length).map(|_| self.elementType.getDefaultValue()).collect(),
+        }));
+    }
+
+    fn getArrayType(&self) -> Option<&ArrayType> {
+        return Some(self);
+    }
+}
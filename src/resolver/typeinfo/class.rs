@@ -4,10 +4,8 @@ use hashbrown::hash_map::Entry;
 use hashbrown::HashMap;
 use llvm_sys::core::LLVMStructTypeInContext;
 use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
-use parking_lot::Mutex;
 
 use crate::ast::visibility::Visibility;
-use crate::resolver::resolutionerror::ResolutionError;
 use crate::resolver::resolvedast::defaultclass::DefaultClass;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::typeinfo::{Type, TypeInfo, TypeProperty};
@@ -24,35 +22,30 @@ pub struct ClassTypeInfo {
     name: String,
     propertyMap: HashMap<String, TypeProperty>,
     explicitConversions: Vec<Type>,
-    llvmType: Mutex<Option<SendLLVMTypeRef>>,
 }
 
-#[derive(Debug)]
-struct SendLLVMTypeRef(LLVMTypeRef);
-
-unsafe impl Send for SendLLVMTypeRef {}
-
 impl ClassTypeInfo {
     pub fn newBuilder(name: impl Into<String>) -> Self {
         return Self {
             name: name.into(),
             propertyMap: HashMap::new(),
             explicitConversions: Vec::new(),
-            llvmType: Default::default(),
         };
     }
 
-    pub fn addFieldFrom(&mut self, ty: Type, name: String) -> Result<(), ResolutionError> {
+    // returns the existing property if a field with the same name has already been added
+    pub fn addFieldFrom(&mut self, ty: Type, name: String, defaultValue: Option<ResolvedExpr>) -> Result<(), TypeProperty> {
         let index = self.propertyMap.len();
         return match self.propertyMap.entry(name.to_owned()) {
-            Entry::Occupied(_) => {
-                Err(ResolutionError::ConflictingFields(self.name.to_owned(), name))
+            Entry::Occupied(entry) => {
+                Err(entry.get().to_owned())
             }
             Entry::Vacant(entry) => {
                 entry.insert(TypeProperty {
                     ty,
                     name,
                     index,
+                    defaultValue,
                 });
                 Ok(())
             }
@@ -70,12 +63,15 @@ impl TypeInfo for ClassTypeInfo {
     }
 
     fn getLLVMType(&self, context: LLVMContextRef) -> LLVMTypeRef {
-        return self.llvmType.lock().get_or_insert_with(|| {
-            let mut llvmTypes = self.propertyMap.iter().map(|(_, property)| property.ty.getLLVMType(context)).collect::<Vec<_>>();
-            unsafe {
-                return SendLLVMTypeRef(LLVMStructTypeInContext(context, llvmTypes.as_mut_ptr(), llvmTypes.len() as _, 0 as _));
-            }
-        }).0;
+        // not cached, as types (such as String) are shared between contexts
+        // literal struct types are unique within a context, so the same type is returned for each call
+        let mut properties = self.propertyMap.values().collect::<Vec<_>>();
+        // struct fields are ordered by property index
+        properties.sort_by_key(|property| property.index);
+        let mut llvmTypes = properties.iter().map(|property| property.ty.getLLVMType(context)).collect::<Vec<_>>();
+        return unsafe {
+            LLVMStructTypeInContext(context, llvmTypes.as_mut_ptr(), llvmTypes.len() as _, 0 as _)
+        };
     }
 
     fn getExplicitConversions(&self) -> &Vec<Type> {
@@ -0,0 +1,76 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use llvm_sys::core::LLVMInt32TypeInContext;
+use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
+use once_cell::sync::Lazy;
+
+use crate::resolver::resolvedast::literalenum::LiteralEnum;
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::typeinfo::{Type, TypeInfo};
+
+// enum values are stored as the index of their variant
+#[derive(Debug)]
+pub struct EnumTypeInfo {
+    name: String,
+    variants: Vec<String>,
+}
+
+impl EnumTypeInfo {
+    pub fn newBuilder(name: impl Into<String>) -> Self {
+        return Self {
+            name: name.into(),
+            variants: Vec::new(),
+        };
+    }
+
+    // returns the index of the existing variant if a variant with the same name has already been added
+    pub fn addVariant(&mut self, name: String) -> Result<(), usize> {
+        if let Some(index) = self.getVariant(&name) {
+            return Err(index);
+        }
+        self.variants.push(name);
+        return Ok(());
+    }
+
+    pub fn build(self) -> Type {
+        return Type(Arc::new(self));
+    }
+
+    pub fn getVariant(&self, name: &str) -> Option<usize> {
+        return self.variants.iter().position(|variant| variant == name);
+    }
+
+    pub fn getVariants(&self) -> &Vec<String> {
+        return &self.variants;
+    }
+}
+
+impl TypeInfo for EnumTypeInfo {
+    fn getTypeName(&self) -> &str {
+        return &self.name;
+    }
+
+    fn getLLVMType(&self, context: LLVMContextRef) -> LLVMTypeRef {
+        return unsafe {
+            LLVMInt32TypeInContext(context)
+        };
+    }
+
+    fn getExplicitConversions(&self) -> &Vec<Type> {
+        static EMPTY_VEC: Lazy<Vec<Type>> = Lazy::new(|| Vec::new());
+        return EMPTY_VEC.deref();
+    }
+
+    fn getDefaultValue(&self, ty: Type) -> ResolvedExpr {
+        // first declared variant
+        return ResolvedExpr::LiteralEnum(LiteralEnum {
+            ty,
+            variant: 0,
+        });
+    }
+
+    fn getEnumType(&self) -> Option<&EnumTypeInfo> {
+        return Some(self);
+    }
+}
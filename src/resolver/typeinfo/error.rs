@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
+use once_cell::sync::Lazy;
+
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::typeinfo::{Type, TypeInfo};
+
+// poison type, given to values which failed to resolve
+// an error has always been reported for values of this type, so further errors involving them are suppressed
+pub static ERROR_TYPE: Lazy<Type> = Lazy::new(|| Type(Arc::new(Error { explicitConversions: vec![] })));
+
+pub struct Error {
+    explicitConversions: Vec<Type>,
+}
+
+impl TypeInfo for Error {
+    fn getTypeName(&self) -> &str {
+        return "<error>";
+    }
+
+    fn getLLVMType(&self, _context: LLVMContextRef) -> LLVMTypeRef {
+        unreachable!("error type cannot be emitted")
+    }
+
+    fn getExplicitConversions(&self) -> &Vec<Type> {
+        return &self.explicitConversions;
+    }
+
+    fn getDefaultValue(&self, _ty: Type) -> ResolvedExpr {
+        unreachable!()
+    }
+}
@@ -7,8 +7,9 @@ use once_cell::sync::Lazy;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr::LiteralBool;
 use crate::resolver::typeinfo::{Type, TypeInfo};
+use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
 
-pub static BOOLEAN_TYPE: Lazy<Type> = Lazy::new(|| Type(Arc::new(Boolean { explicitConversions: vec![] })));
+pub static BOOLEAN_TYPE: Lazy<Type> = Lazy::new(|| Type(Arc::new(Boolean { explicitConversions: vec![INTEGER_TYPE.to_owned()] })));
 
 pub struct Boolean {
     explicitConversions: Vec<Type>,
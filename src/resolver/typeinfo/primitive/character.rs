@@ -7,8 +7,9 @@ use once_cell::sync::Lazy;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr::LiteralChar;
 use crate::resolver::typeinfo::{Type, TypeInfo};
+use crate::resolver::typeinfo::primitive::integer::INTEGER_TYPE;
 
-pub static CHARACTER_TYPE: Lazy<Type> = Lazy::new(|| Type(Arc::new(Character { explicitConversions: vec![] })));
+pub static CHARACTER_TYPE: Lazy<Type> = Lazy::new(|| Type(Arc::new(Character { explicitConversions: vec![INTEGER_TYPE.to_owned()] })));
 
 pub struct Character {
     explicitConversions: Vec<Type>,
@@ -7,6 +7,7 @@ use once_cell::sync::Lazy;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
 use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr::LiteralInteger;
 use crate::resolver::typeinfo::{Type, TypeInfo};
+use crate::resolver::typeinfo::primitive::character::CHARACTER_TYPE;
 use crate::resolver::typeinfo::primitive::float::FLOAT_TYPE;
 
 pub static INTEGER_TYPE: Lazy<Type> = Lazy::new(|| Integer::new("int"));
@@ -35,7 +36,7 @@ impl TypeInfo for Integer {
     }
 
     fn getExplicitConversions(&self) -> &Vec<Type> {
-        static EXPLICIT_CONVERSIONS: Lazy<Vec<Type>> = Lazy::new(|| vec![FLOAT_TYPE.to_owned()]);
+        static EXPLICIT_CONVERSIONS: Lazy<Vec<Type>> = Lazy::new(|| vec![FLOAT_TYPE.to_owned(), CHARACTER_TYPE.to_owned()]);
         return &EXPLICIT_CONVERSIONS;
     }
 
@@ -8,8 +8,8 @@ use crate::resolver::typeinfo::Type;
 
 pub static STRING_TYPE: Lazy<Type> = Lazy::new(|| {
     let mut classType = ClassTypeInfo::newBuilder("String");
-    classType.addFieldFrom(INTEGER_TYPE.to_owned(), "length".to_owned()).expect("failed to create string type");
-    classType.addFieldFrom(PointerType::new(CHARACTER_TYPE.to_owned()), "pointer".to_owned()).expect("failed to create string type");
+    classType.addFieldFrom(INTEGER_TYPE.to_owned(), "length".to_owned(), None).expect("failed to create string type");
+    classType.addFieldFrom(PointerType::new(CHARACTER_TYPE.to_owned()), "pointer".to_owned(), None).expect("failed to create string type");
 
     classType.build()
 });
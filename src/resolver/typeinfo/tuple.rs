@@ -0,0 +1,94 @@
+use std::ops::Deref;
+use std::sync::Arc;
+
+use hashbrown::HashMap;
+use llvm_sys::core::LLVMStructTypeInContext;
+use llvm_sys::prelude::{LLVMContextRef, LLVMTypeRef};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+
+use crate::resolver::resolvedast::literaltuple::LiteralTuple;
+use crate::resolver::resolvedast::resolvedexpr::ResolvedExpr;
+use crate::resolver::typeinfo::{Type, TypeInfo, TypeProperty};
+
+// anonymous structure of values (e.g. (int, float)), fields are named by position (t.0, t.1)
+pub struct TupleType {
+    typeName: String,
+    pub elementTypes: Vec<Type>,
+    propertyMap: HashMap<String, TypeProperty>,
+}
+
+impl TupleType {
+    // types are compared by identity, so each list of element types is only created once
+    pub fn new(elementTypes: Vec<Type>) -> Type {
+        static TUPLE_TYPES: Lazy<Mutex<HashMap<Vec<Type>, Type>>> = Lazy::new(|| Mutex::new(HashMap::new()));
+        return TUPLE_TYPES.lock().entry(elementTypes.to_owned()).or_insert_with(|| {
+            let typeName = format!("({})", elementTypes.iter().map(|ty| ty.getTypeName()).collect::<Vec<_>>().join(", "));
+            let propertyMap = elementTypes.iter().enumerate().map(|(index, ty)| (index.to_string(), TypeProperty {
+                ty: ty.to_owned(),
+                name: index.to_string(),
+                index,
+                defaultValue: None,
+            })).collect();
+            Type(Arc::new(Self {
+                typeName,
+                elementTypes,
+                propertyMap,
+            }))
+        }).to_owned();
+    }
+}
+
+// splits a tuple type name into its element names (e.g. "(int, (float, bool))" into "int" and "(float, bool)")
+pub fn splitTupleTypeName(name: &str) -> Option<Vec<&str>> {
+    let inner = name.strip_prefix('(')?.strip_suffix(')')?;
+    let mut elementNames = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, character) in inner.char_indices() {
+        match character {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            ',' if depth == 0 => {
+                elementNames.push(inner[start..index].trim());
+                start = index + 1;
+            }
+            _ => {}
+        }
+    }
+    elementNames.push(inner[start..].trim());
+    return Some(elementNames);
+}
+
+impl TypeInfo for TupleType {
+    fn getTypeName(&self) -> &str {
+        return &self.typeName;
+    }
+
+    fn getLLVMType(&self, context: LLVMContextRef) -> LLVMTypeRef {
+        let mut elementTypes = self.elementTypes.iter().map(|ty| ty.getLLVMType(context)).collect::<Vec<_>>();
+        return unsafe {
+            LLVMStructTypeInContext(context, elementTypes.as_mut_ptr(), elementTypes.len() as _, 0)
+        };
+    }
+
+    fn getExplicitConversions(&self) -> &Vec<Type> {
+        static EMPTY_VEC: Lazy<Vec<Type>> = Lazy::new(|| Vec::new());
+        return EMPTY_VEC.deref();
+    }
+
+    fn getDefaultValue(&self, ty: Type) -> ResolvedExpr {
+        return ResolvedExpr::LiteralTuple(Box::new(LiteralTuple {
+            ty,
+            valueVec: self.elementTypes.iter().map(|ty| ty.getDefaultValue()).collect(),
+        }));
+    }
+
+    fn getPropertyMap(&self) -> &HashMap<String, TypeProperty> {
+        return &self.propertyMap;
+    }
+
+    fn getTupleType(&self) -> Option<&TupleType> {
+        return Some(self);
+    }
+}